@@ -0,0 +1,428 @@
+//! kovi-event-extra 的派生宏
+//!
+//! 请通过 `kovi_event_extra::event::notice::NoticeEvent` 使用，而不是直接依赖本 crate
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Ident,
+    LitStr,
+    Path,
+    Result,
+    Token,
+    bracketed,
+    parse_macro_input
+};
+
+/// 为通知事件结构体生成 `TryFrom<NoticeEvent>`、`TryFrom<&NoticeEvent>`、`Deref`、`TypedNoticeEvent` 实现与 `from_json` 方法
+///
+/// 结构体还需要派生 `StructName`，这是 `TypedNoticeEvent` 的要求
+///
+/// 两种转换都直接在借用上校验与读取字段，`TryFrom<&NoticeEvent>` 只在转换成功时克隆原始事件
+///
+/// # 结构体属性
+/// - `#[notice(type = "...")]`：必须，`notice_type` 的值
+/// - `#[notice(sub_types = ["...", ...])]`：可选，允许的 `sub_type`，为空时不限制
+///
+/// 两者在 `DESCRIPTOR` 中生成对应的 `NoticeType`、`SubType` 变体，未知的取值生成 `Other`
+///
+/// # 字段
+/// - 总是校验 `post_type`、`notice_type`，以及限制了取值的 `sub_type`，
+///   结构体中同名的字段会使用校验的结果
+/// - `original_event` 保存原始的 `NoticeEvent`，同时作为 `Deref` 的目标
+/// - 其余字段按字段名从 JSON 中读取，类型需要实现 `FromField`
+///
+/// # 字段属性
+/// - `#[notice(rename = "...")]`：使用另一个 JSON 键名
+/// - `#[notice(optional)]`：字段类型为 `Option<T>`，不存在时为 `None`
+/// - `#[notice(with = "path")]`：使用 `fn(&Value) -> Option<T>` 读取字段
+/// - `#[notice(into = "path")]`：启用 `serde` 特性时，使用 `fn(&T) -> impl Serialize` 输出字段
+///
+/// # 序列化
+/// 启用 `kovi_event_extra` 的 `serde` 特性时，同时为不带泛型的结构体实现 `Serialize` 与 `Deserialize`：
+/// - 序列化输出 `time`、`self_id` 与其余字段，键名与 OneBot 一致，不包含 `original_event`
+/// - 反序列化时将 JSON 作为原始事件，并与 `TryFrom<NoticeEvent>` 进行相同的校验
+///
+/// ```rust,ignore
+/// use kovi::NoticeEvent;
+/// use kovi_event_extra::event::PostType;
+/// use kovi_event_extra::event::notice::{NoticeEvent, NoticeType, SubType};
+/// use struct_name_macro::StructName;
+///
+/// #[derive(Debug, Clone, StructName, NoticeEvent)]
+/// #[notice(type = "notify", sub_types = ["group_name"])]
+/// pub struct GroupNameNoticeEvent {
+///     pub post_type: PostType,
+///     pub notice_type: NoticeType,
+///     pub sub_type: SubType,
+///     pub group_id: i64,
+///     #[notice(rename = "name_new")]
+///     pub name: String,
+///
+///     original_event: NoticeEvent
+/// }
+/// ```
+#[proc_macro_derive(NoticeEvent, attributes(notice))]
+pub fn derive_notice_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// 结构体上的 `#[notice(...)]`
+struct NoticeAttr {
+    notice_type: LitStr,
+    sub_types: Vec<LitStr>
+}
+
+/// 字段上的 `#[notice(...)]`
+#[derive(Default)]
+struct FieldAttr {
+    rename: Option<LitStr>,
+    optional: bool,
+    with: Option<Path>,
+    into: Option<Path>
+}
+
+fn parse_notice_attr(input: &DeriveInput) -> Result<NoticeAttr> {
+    let mut notice_type = None;
+    let mut sub_types = Vec::new();
+
+    for attr in input.attrs.iter().filter(|it| it.path().is_ident("notice")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type") {
+                notice_type = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("sub_types") {
+                let value = meta.value()?;
+                let content;
+                bracketed!(content in value);
+                sub_types.extend(
+                    Punctuated::<LitStr, Token![,]>::parse_terminated(
+                        &content
+                    )?
+                );
+                Ok(())
+            } else {
+                Err(meta.error("expected `type` or `sub_types`"))
+            }
+        })?;
+    }
+
+    let notice_type = notice_type.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "missing `#[notice(type = \"...\")]` attribute"
+        )
+    })?;
+
+    Ok(NoticeAttr {
+        notice_type,
+        sub_types
+    })
+}
+
+fn parse_field_attr(field: &syn::Field) -> Result<FieldAttr> {
+    let mut it = FieldAttr::default();
+
+    for attr in field.attrs.iter().filter(|it| it.path().is_ident("notice")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                it.rename = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("optional") {
+                it.optional = true;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                it.with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("into") {
+                it.into = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta
+                    .error("expected `rename`, `optional`, `with` or `into`"))
+            }
+        })?;
+    }
+
+    Ok(it)
+}
+
+/// `NoticeType` 已知变体对应的取值
+const NOTICE_TYPES: &[&str] = &[
+    "group_upload",
+    "group_admin",
+    "group_decrease",
+    "group_increase",
+    "group_ban",
+    "friend_add",
+    "group_recall",
+    "friend_recall",
+    "notify"
+];
+
+/// `SubType` 已知变体对应的取值
+const SUB_TYPES: &[&str] = &[
+    "set",
+    "unset",
+    "leave",
+    "kick",
+    "kick_me",
+    "approve",
+    "invite",
+    "ban",
+    "lift_ban",
+    "poke",
+    "lucky_king",
+    "honor"
+];
+
+/// 把取值转换为枚举变体的路径，例如 `lift_ban` 转换为 `SubType::LiftBan`，
+/// 未知的取值转换为 `Other`
+fn variant(ty: TokenStream2, known: &[&str], value: &LitStr) -> TokenStream2 {
+    let it = value.value();
+
+    if !known.contains(&it.as_str()) {
+        return quote! {
+            #ty::Other(::kovi_event_extra::__private::unknown_value(#value))
+        };
+    }
+
+    let name = it
+        .split('_')
+        .map(|it| {
+            let mut chars = it.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string())
+                .unwrap_or_default()
+                + chars.as_str()
+        })
+        .collect::<String>();
+    let name = Ident::new(&name, value.span());
+
+    quote!(#ty::#name)
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "NoticeEvent can only be derived for structs"
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "NoticeEvent can only be derived for structs with named fields"
+        ));
+    };
+
+    let attr = parse_notice_attr(&input)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let target = ident.to_string();
+    let notice_type = variant(
+        quote!(::kovi_event_extra::event::notice::NoticeType),
+        NOTICE_TYPES,
+        &attr.notice_type
+    );
+    let sub_types = attr
+        .sub_types
+        .iter()
+        .map(|it| {
+            variant(
+                quote!(::kovi_event_extra::event::notice::SubType),
+                SUB_TYPES,
+                it
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let private = quote!(::kovi_event_extra::__private);
+    let descriptor = quote! {
+        <Self as ::kovi_event_extra::event::notice::TypedNoticeEvent>::DESCRIPTOR
+    };
+
+    let mut original = None;
+    let mut has_sub_type = false;
+    let mut bindings = Vec::new();
+    let mut idents = Vec::new();
+    let mut entries = Vec::new();
+
+    for field in &fields.named {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let field_attr = parse_field_attr(field)?;
+
+        let key = field_attr
+            .rename
+            .map(|it| it.value())
+            .unwrap_or_else(|| name.to_string());
+        let into = field_attr
+            .into
+            .map(|it| quote!(#it))
+            .unwrap_or_else(|| quote!(#private::same));
+
+        let value = match name.to_string().as_str() {
+            "original_event" => {
+                original = Some(name.clone());
+                continue;
+            }
+            "post_type" => quote!(__post_type),
+            "notice_type" => quote!(__notice_type),
+            "sub_type" => {
+                has_sub_type = true;
+                quote!(__sub_type)
+            }
+            _ => match (field_attr.optional, field_attr.with) {
+                (true, Some(with)) => quote! {
+                    __json.get(#key).and_then(#with)
+                },
+                (true, None) => quote! {
+                    #private::optional_field(__json, #key)
+                },
+                (false, Some(with)) => quote! {
+                    #private::field_with::<#ty>(__json, #key, stringify!(#ty), #target, #with)?
+                },
+                (false, None) => quote! {
+                    #private::field::<#ty>(__json, #key, stringify!(#ty), #target)?
+                }
+            }
+        };
+
+        entries.push(quote!(#key => #into { #name }));
+        bindings.push(quote!(let #name = #value;));
+        idents.push(name.clone());
+    }
+
+    let original: Ident = original.ok_or_else(|| {
+        Error::new_spanned(ident, "missing `original_event: NoticeEvent` field")
+    })?;
+
+    // 即使结构体没有对应的字段也要校验事件类型，只在有字段时使用结果。
+    // `sub_type` 只在限制了取值或需要字段时读取，因为部分通知事件没有 `sub_type`
+    let sub_type = (has_sub_type || !sub_types.is_empty()).then(|| {
+        quote! {
+            let __sub_type =
+                #private::sub_type(__json, #descriptor.sub_types, #target)?;
+        }
+    });
+    let checks = quote! {
+        let __post_type = #private::post_type(__event, #target)?;
+        let __notice_type =
+            #private::notice_type(__event, &#descriptor.notice_type, #target)?;
+        #sub_type
+    };
+
+    // `__impl_serde!` 只接受不带泛型的类型
+    let serde = input.generics.params.is_empty().then(|| {
+        quote! {
+            ::kovi_event_extra::__impl_serde! {
+                #ident;
+                from = #private::notice_event;
+                "time" => #private::same { #original.time },
+                "self_id" => #private::same { #original.self_id },
+                #(#entries),*
+            }
+        }
+    });
+
+    Ok(quote! {
+        #serde
+
+        impl #impl_generics ::kovi_event_extra::event::notice::TypedNoticeEvent
+            for #ident #ty_generics #where_clause
+        {
+            const DESCRIPTOR: ::kovi_event_extra::event::notice::NoticeDescriptor =
+                ::kovi_event_extra::event::notice::NoticeDescriptor {
+                    notice_type: #notice_type,
+                    sub_types: &[#(#sub_types),*]
+                };
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// 从原始 JSON 解析，与 `TryFrom<NoticeEvent>` 进行相同的校验
+            ///
+            /// 不依赖 Kovi 运行时，可以用于解析 OneBot 事件日志
+            pub fn from_json(
+                json: &#private::serde_json::Value
+            ) -> ::core::result::Result<Self, ::kovi_event_extra::event::Error> {
+                let mut __header = #private::notice_header(json)?;
+                let __event = &__header;
+                #[allow(unused_variables)]
+                let __json = json;
+
+                #checks
+                #(#bindings)*
+
+                // 校验全部通过后才克隆原始 JSON
+                __header.original_json = ::core::clone::Clone::clone(json);
+
+                ::core::result::Result::Ok(Self {
+                    #(#idents,)*
+                    #original: __header
+                })
+            }
+        }
+
+        impl #impl_generics ::core::ops::Deref for #ident #ty_generics #where_clause {
+            type Target = #private::NoticeEvent;
+
+            fn deref(&self) -> &Self::Target { &self.#original }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#private::NoticeEvent>
+            for #ident #ty_generics #where_clause
+        {
+            type Error = ::kovi_event_extra::event::Error;
+
+            fn try_from(
+                __value: #private::NoticeEvent
+            ) -> ::core::result::Result<Self, Self::Error> {
+                let __event = &__value;
+                #[allow(unused_variables)]
+                let __json = &__event.original_json;
+
+                #checks
+                #(#bindings)*
+
+                ::core::result::Result::Ok(Self {
+                    #(#idents,)*
+                    #original: __value
+                })
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<&#private::NoticeEvent>
+            for #ident #ty_generics #where_clause
+        {
+            type Error = ::kovi_event_extra::event::Error;
+
+            fn try_from(
+                __event: &#private::NoticeEvent
+            ) -> ::core::result::Result<Self, Self::Error> {
+                #[allow(unused_variables)]
+                let __json = &__event.original_json;
+
+                #checks
+                #(#bindings)*
+
+                ::core::result::Result::Ok(Self {
+                    #(#idents,)*
+                    #original: ::core::clone::Clone::clone(__event)
+                })
+            }
+        }
+    })
+}
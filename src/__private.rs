@@ -0,0 +1,220 @@
+//! 供派生宏生成的代码使用，不属于公开 API
+
+#[cfg(feature = "kovi")]
+pub use kovi;
+#[cfg(feature = "serde")]
+pub use serde;
+pub use serde_json;
+use serde_json::Value;
+
+pub use crate::compat::NoticeEvent;
+use crate::compat::RequestEvent;
+use crate::event::notice::{NoticeType, SubType};
+use crate::event::{Error, FromField, PostType, UnknownValue};
+
+fn unable_convert(target: &str, because: Error) -> Error {
+    unable_convert_from("NoticeEvent", target, because)
+}
+
+fn unable_convert_from(source: &str, target: &str, because: Error) -> Error {
+    Error::UnableConvert {
+        source_event: String::from(source),
+        target_event: String::from(target),
+        because: Box::new(because)
+    }
+}
+
+fn unexpected_value<T: ToString>(
+    field: &str,
+    expected: &[T],
+    actual: String
+) -> Error {
+    Error::UnexpectedValue {
+        field: String::from(field),
+        expected: expected.iter().map(|it| it.to_string()).collect(),
+        actual,
+        because: None
+    }
+}
+
+pub fn post_type(event: &NoticeEvent, target: &str) -> Result<PostType, Error> {
+    let it = PostType::try_from(event.post_type.as_str())
+        .map_err(|it| unable_convert(target, it))?;
+
+    if it != PostType::Notice {
+        let because =
+            unexpected_value("post_type", &["notice"], it.to_string());
+        return Err(unable_convert(target, because));
+    }
+
+    Ok(it)
+}
+
+pub fn notice_type(
+    event: &NoticeEvent,
+    expected: &NoticeType,
+    target: &str
+) -> Result<NoticeType, Error> {
+    let it = NoticeType::parse_lenient(event.notice_type.as_str());
+
+    if it != *expected {
+        let because =
+            unexpected_value("notice_type", &[expected], it.to_string());
+        return Err(unable_convert(target, because));
+    }
+
+    Ok(it)
+}
+
+pub fn sub_type(
+    json: &Value,
+    expected: &[SubType],
+    target: &str
+) -> Result<SubType, Error> {
+    let it = field_with(json, "sub_type", "str", target, |it| it.as_str())?;
+    let it = SubType::parse_lenient(it);
+
+    if !expected.is_empty() && !expected.contains(&it) {
+        let because = unexpected_value("sub_type", expected, it.to_string());
+        return Err(unable_convert(target, because));
+    }
+
+    Ok(it)
+}
+
+/// 在常量中构造未知的枚举值，派生宏只对不属于已知变体的字符串使用
+pub const fn unknown_value(value: &'static str) -> UnknownValue {
+    UnknownValue::from_static(value)
+}
+
+pub fn field<T: FromField>(
+    json: &Value,
+    key: &str,
+    expected: &str,
+    target: &str
+) -> Result<T, Error> {
+    let it = field_with(json, key, expected, target, T::try_from_field)?;
+
+    it.map_err(|it| {
+        let because = Error::UnexpectedValue {
+            field: String::from(key),
+            expected: vec![String::from(expected)],
+            actual: match &json[key] {
+                Value::String(it) => it.clone(),
+                it => it.to_string()
+            },
+            because: Some(Box::new(it))
+        };
+        unable_convert(target, because)
+    })
+}
+
+pub fn field_with<'a, T>(
+    json: &'a Value,
+    key: &str,
+    expected: &str,
+    target: &str,
+    with: impl FnOnce(&'a Value) -> Option<T>
+) -> Result<T, Error> {
+    read(json, key, expected, with).map_err(|it| unable_convert(target, it))
+}
+
+pub fn optional_field<T: FromField>(json: &Value, key: &str) -> Option<T> {
+    json.get(key).and_then(T::from_field)
+}
+
+/// 序列化时原样输出字段
+pub fn same<T>(it: &T) -> &T { it }
+
+/// 序列化消息事件时输出原始 JSON 中的消息内容
+pub fn message(json: &Value) -> &Value { &json["message"] }
+
+/// 从 JSON 构造 [`NoticeEvent`]，用于反序列化
+pub fn notice_event(json: Value) -> Result<NoticeEvent, Error> {
+    let mut it = notice_header(&json)?;
+    it.original_json = json;
+
+    Ok(it)
+}
+
+/// 只读取 [`NoticeEvent`] 的公共字段，`original_json` 留空
+///
+/// 供 `from_json` 在借用的 JSON 上完成校验，成功后再克隆
+pub fn notice_header(json: &Value) -> Result<NoticeEvent, Error> {
+    let target = "NoticeEvent";
+
+    Ok(NoticeEvent {
+        time: json_field(json, "time", "i64", target, Value::as_i64)?,
+        self_id: json_field(json, "self_id", "i64", target, Value::as_i64)?,
+        post_type: json_field(json, "post_type", "str", target, Value::as_str)?
+            .to_string(),
+        notice_type: json_field(
+            json,
+            "notice_type",
+            "str",
+            target,
+            Value::as_str
+        )?
+        .to_string(),
+        original_json: Value::Null
+    })
+}
+
+/// 从 JSON 构造 [`RequestEvent`]，用于反序列化
+pub fn request_event(json: Value) -> Result<RequestEvent, Error> {
+    let target = "RequestEvent";
+
+    Ok(RequestEvent {
+        time: json_field(&json, "time", "i64", target, Value::as_i64)?,
+        self_id: json_field(&json, "self_id", "i64", target, Value::as_i64)?,
+        post_type: json_field(
+            &json,
+            "post_type",
+            "str",
+            target,
+            Value::as_str
+        )?
+        .to_string(),
+        request_type: json_field(
+            &json,
+            "request_type",
+            "str",
+            target,
+            Value::as_str
+        )?
+        .to_string(),
+        original_json: json
+    })
+}
+
+/// 原样返回 JSON，用于以 [`Value`] 为原始事件的结构体的反序列化
+pub fn value(json: Value) -> Result<Value, Error> { Ok(json) }
+
+fn json_field<'a, T>(
+    json: &'a Value,
+    key: &str,
+    expected: &str,
+    target: &str,
+    with: impl FnOnce(&'a Value) -> Option<T>
+) -> Result<T, Error> {
+    read(json, key, expected, with)
+        .map_err(|it| unable_convert_from("Value", target, it))
+}
+
+fn read<'a, T>(
+    json: &'a Value,
+    key: &str,
+    expected: &str,
+    with: impl FnOnce(&'a Value) -> Option<T>
+) -> Result<T, Error> {
+    let Some(it) = json.get(key) else {
+        return Err(Error::MissingField {
+            field: String::from(key)
+        });
+    };
+
+    with(it).ok_or_else(|| Error::WrongFieldType {
+        field: String::from(key),
+        expected: String::from(expected)
+    })
+}
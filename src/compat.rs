@@ -0,0 +1,44 @@
+//! 原始事件类型
+//!
+//! 启用 `kovi` 特性（默认启用）时直接使用 Kovi 的事件类型；
+//! 关闭后使用本模块中字段相同的替代定义，使事件模型可以脱离 Kovi 运行时使用，
+//! 例如在离线工具中通过 `from_json` 解析 OneBot 事件日志。
+//!
+//! 消息事件只依赖原始 JSON，因此 `MsgEvent` 只在启用 `kovi` 特性时导出。
+
+#[cfg(feature = "kovi")]
+pub use kovi::{MsgEvent, NoticeEvent, RequestEvent};
+#[cfg(not(feature = "kovi"))]
+use serde_json::Value;
+
+/// 通知事件，字段与 Kovi 的 `NoticeEvent` 一致
+#[cfg(not(feature = "kovi"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoticeEvent {
+    /// 事件发生的时间戳
+    pub time: i64,
+    /// 收到事件的机器人 QQ 号
+    pub self_id: i64,
+    /// 上报类型
+    pub post_type: String,
+    /// 通知类型
+    pub notice_type: String,
+    /// 原始的 JSON
+    pub original_json: Value
+}
+
+/// 请求事件，字段与 Kovi 的 `RequestEvent` 一致
+#[cfg(not(feature = "kovi"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestEvent {
+    /// 事件发生的时间戳
+    pub time: i64,
+    /// 收到事件的机器人 QQ 号
+    pub self_id: i64,
+    /// 上报类型
+    pub post_type: String,
+    /// 请求类型
+    pub request_type: String,
+    /// 原始的 JSON
+    pub original_json: Value
+}
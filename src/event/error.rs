@@ -1,6 +1,8 @@
-use std::fmt;
-use std::ops::Deref;
+#[cfg(feature = "i18n")]
+use std::borrow::Cow;
+use std::{error, fmt};
 
+#[cfg(feature = "i18n")]
 use rust_i18n::t;
 
 /// 错误枚举
@@ -10,40 +12,233 @@ pub enum Error {
     UnknownPostType(String),
     /// 未知消息类型
     UnknownNoticeType(String),
+    /// 未知消息事件类型
+    UnknownMessageType(String),
+    /// 未知元事件类型
+    UnknownMetaEventType(String),
+    /// 未知请求类型
+    UnknownRequestType(String),
     /// 未知子类型
     UnknownSubType(String),
+    /// 未知荣誉类型
+    UnknownHonorType(String),
+    /// 未知群成员角色
+    UnknownRole(String),
+    /// 缺少字段
+    MissingField {
+        /// 字段名
+        field: String
+    },
+    /// 字段类型错误
+    WrongFieldType {
+        /// 字段名
+        field: String,
+        /// 期望的类型
+        expected: String
+    },
+    /// 字段的值不符合预期
+    UnexpectedValue {
+        /// 字段名
+        field: String,
+        /// 期望的值
+        expected: Vec<String>,
+        /// 实际的值
+        actual: String,
+        /// 无法识别该值的内部错误
+        because: Option<Box<Error>>
+    },
     /// 事件类型转换错误
     UnableConvert {
         /// 源事件类型
         source_event: String,
         /// 目标事件类型
         target_event: String,
-        /// 转换失败原因
-        because: String
+        /// 转换失败原因，不包含在错误消息中，可以通过 [`source`](error::Error::source) 获取
+        because: Box<Error>
+    },
+    /// 无法加载消息模板
+    UnableLoadTemplate(String)
+}
+
+impl Error {
+    /// 输出错误消息以及 [`source`](error::Error::source) 中的所有内部错误，以 `: ` 分隔
+    ///
+    /// [`Display`](fmt::Display) 只输出最外层的错误，记录日志时可以使用此方法输出完整的原因：
+    ///
+    /// ```rust
+    /// use kovi_event_extra::event::Error;
+    ///
+    /// let because = Error::MissingField {
+    ///     field: String::from("user_id")
+    /// };
+    /// let it = Error::UnableConvert {
+    ///     source_event: String::from("NoticeEvent"),
+    ///     target_event: String::from("PokeNoticeEvent"),
+    ///     because: Box::new(because.clone())
+    /// };
+    ///
+    /// assert_eq!(it.display_chain(), format!("{}: {}", it, because));
+    /// ```
+    pub fn display_chain(&self) -> String {
+        let mut it = self.to_string();
+        let mut source = error::Error::source(self);
+
+        while let Some(because) = source {
+            it.push_str(": ");
+            it.push_str(&because.to_string());
+            source = because.source();
+        }
+
+        it
     }
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let t = match self {
+#[cfg(feature = "i18n")]
+impl Error {
+    /// 使用指定的语言输出错误消息，不受 [`set_locale`](crate::set_locale) 影响
+    ///
+    /// ```rust
+    /// use kovi_event_extra::event::Error;
+    ///
+    /// let it = Error::UnknownPostType(String::from("unknown"));
+    ///
+    /// assert_eq!(it.display_in("en-US"), "Unknown post type: unknown");
+    /// ```
+    pub fn display_in(&self, locale: &str) -> String {
+        self.translate(locale).into_owned()
+    }
+
+    fn translate(&self, locale: &str) -> Cow<'_, str> {
+        match self {
             Self::UnknownPostType(it) => {
-                t!("event.error.Error.UnknownPostType", it => it)
+                t!("event.error.Error.UnknownPostType", locale = locale, it => it)
             }
             Self::UnknownNoticeType(it) => {
-                t!("event.error.Error.UnknownNoticeType", it => it)
+                t!("event.error.Error.UnknownNoticeType", locale = locale, it => it)
+            }
+            Self::UnknownMessageType(it) => {
+                t!("event.error.Error.UnknownMessageType", locale = locale, it => it)
+            }
+            Self::UnknownMetaEventType(it) => {
+                t!("event.error.Error.UnknownMetaEventType", locale = locale, it => it)
+            }
+            Self::UnknownRequestType(it) => {
+                t!("event.error.Error.UnknownRequestType", locale = locale, it => it)
             }
             Self::UnknownSubType(it) => {
-                t!("event.error.Error.UnknownSubType", it => it)
+                t!("event.error.Error.UnknownSubType", locale = locale, it => it)
+            }
+            Self::UnknownHonorType(it) => {
+                t!("event.error.Error.UnknownHonorType", locale = locale, it => it)
+            }
+            Self::UnknownRole(it) => {
+                t!("event.error.Error.UnknownRole", locale = locale, it => it)
+            }
+            Self::MissingField { field } => {
+                t!("event.error.Error.MissingField", locale = locale, field => field)
+            }
+            Self::WrongFieldType { field, expected } => {
+                t!("event.error.Error.WrongFieldType", locale = locale, field => field, expected => expected)
+            }
+            Self::UnexpectedValue {
+                field,
+                expected,
+                actual,
+                ..
+            } => {
+                t!("event.error.Error.UnexpectedValue", locale = locale, field => field, expected => expected.join(" | "), actual => actual)
             }
             Self::UnableConvert {
                 source_event,
                 target_event,
-                because
+                ..
             } => {
-                t!("event.error.Error.UnableConvert", source_event => source_event, target_event => target_event, because => because)
+                t!("event.error.Error.UnableConvert", locale = locale, source_event => source_event, target_event => target_event)
             }
-        };
+            Self::UnableLoadTemplate(it) => {
+                t!("event.error.Error.UnableLoadTemplate", locale = locale, it => it)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "i18n")]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.translate(&crate::locale()))
+    }
+}
+
+/// 未启用 `i18n` 特性时使用固定的英文消息，与 `language/en-US.yml` 保持一致
+#[cfg(not(feature = "i18n"))]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPostType(it) => write!(f, "Unknown post type: {}", it),
+            Self::UnknownNoticeType(it) => {
+                write!(f, "Unknown notice type: {}", it)
+            }
+            Self::UnknownMessageType(it) => {
+                write!(f, "Unknown message type: {}", it)
+            }
+            Self::UnknownMetaEventType(it) => {
+                write!(f, "Unknown meta event type: {}", it)
+            }
+            Self::UnknownRequestType(it) => {
+                write!(f, "Unknown request type: {}", it)
+            }
+            Self::UnknownSubType(it) => write!(f, "Unknown sub type: {}", it),
+            Self::UnknownHonorType(it) => {
+                write!(f, "Unknown honor type: {}", it)
+            }
+            Self::UnknownRole(it) => {
+                write!(f, "Unknown group member role: {}", it)
+            }
+            Self::MissingField { field } => {
+                write!(f, "{} does not exist", field)
+            }
+            Self::WrongFieldType { field, expected } => {
+                write!(f, "{} is not of type {}", field, expected)
+            }
+            Self::UnexpectedValue {
+                field,
+                expected,
+                actual,
+                ..
+            } => write!(
+                f,
+                "the value {} of {} is not {}",
+                actual,
+                field,
+                expected.join(" | ")
+            ),
+            Self::UnableConvert {
+                source_event,
+                target_event,
+                ..
+            } => write!(
+                f,
+                "{} cannot be converted to {}",
+                source_event, target_event
+            ),
+            Self::UnableLoadTemplate(it) => {
+                write!(f, "Unable to load template: {}", it)
+            }
+        }
+    }
+}
 
-        f.write_str(t.deref())
+impl error::Error for Error {
+    /// 返回 [`Error::UnableConvert`] 转换失败的原因，
+    /// 或 [`Error::UnexpectedValue`] 中无法识别该值的内部错误
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::UnableConvert { because, .. } => Some(because.as_ref()),
+            Self::UnexpectedValue {
+                because: Some(because),
+                ..
+            } => Some(because.as_ref()),
+            _ => None
+        }
     }
 }
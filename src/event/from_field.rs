@@ -0,0 +1,58 @@
+use serde_json::Value;
+
+use crate::event::Error;
+
+/// 从事件 JSON 的字段中读取值
+///
+/// [`NoticeEvent`](crate::event::notice::NoticeEvent) 派生宏通过此 trait 读取普通字段，
+/// 为自定义类型实现此 trait 后即可直接作为字段类型使用
+pub trait FromField: Sized {
+    /// 从字段的值转换，如果类型不符则返回 [`None`]
+    fn from_field(value: &Value) -> Option<Self>;
+
+    /// 从字段的值转换，并区分类型不符与无法识别的值
+    ///
+    /// 类型不符时返回 [`None`]，类型相符但值无法识别时返回具体的错误，
+    /// 派生宏会将其作为 [`Error::UnexpectedValue`] 的内部错误。默认使用 [`Self::from_field`]
+    fn try_from_field(value: &Value) -> Option<Result<Self, Error>> {
+        Self::from_field(value).map(Ok)
+    }
+}
+
+impl FromField for i64 {
+    fn from_field(value: &Value) -> Option<Self> { value.as_i64() }
+}
+
+impl FromField for i32 {
+    fn from_field(value: &Value) -> Option<Self> {
+        value.as_i64().and_then(|it| it.try_into().ok())
+    }
+}
+
+impl FromField for u64 {
+    fn from_field(value: &Value) -> Option<Self> { value.as_u64() }
+}
+
+impl FromField for u32 {
+    fn from_field(value: &Value) -> Option<Self> {
+        value.as_u64().and_then(|it| it.try_into().ok())
+    }
+}
+
+impl FromField for f64 {
+    fn from_field(value: &Value) -> Option<Self> { value.as_f64() }
+}
+
+impl FromField for bool {
+    fn from_field(value: &Value) -> Option<Self> { value.as_bool() }
+}
+
+impl FromField for String {
+    fn from_field(value: &Value) -> Option<Self> {
+        value.as_str().map(String::from)
+    }
+}
+
+impl FromField for Value {
+    fn from_field(value: &Value) -> Option<Self> { Some(value.clone()) }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
+
+/// 消息事件消息类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MessageType {
+    /// 私聊消息
+    Private,
+    /// 群消息
+    Group,
+    /// 未知的消息类型，保留原始字符串
+    Other(UnknownValue)
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Private => "private",
+            Self::Group => "group",
+            Self::Other(it) => it.as_str()
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl TryFrom<&str> for MessageType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "private" => Ok(Self::Private),
+            "group" => Ok(Self::Group),
+            _ => Err(Self::Error::UnknownMessageType(value.to_string()))
+        }
+    }
+}
+
+impl MessageType {
+    /// 宽松地解析消息类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`MessageType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的消息类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(MessageType);
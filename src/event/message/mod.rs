@@ -0,0 +1,20 @@
+//! 消息事件处理模块
+//!
+//! 包含各类消息事件的具体实现
+
+mod group;
+mod message_type;
+mod private;
+mod role;
+mod segment;
+mod sender;
+mod sub_type;
+
+// 重新导出
+pub use group::{AnonymousInfo, GroupMessageEvent};
+pub use message_type::MessageType;
+pub use private::PrivateMessageEvent;
+pub use role::Role;
+pub use segment::{Data, Extra, Segment};
+pub use sender::Sender;
+pub use sub_type::SubType;
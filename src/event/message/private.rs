@@ -0,0 +1,224 @@
+use std::ops::Deref;
+
+use serde_json::Value;
+use struct_name::StructName;
+use struct_name_macro::StructName;
+
+use super::{MessageType, Segment, Sender, SubType};
+#[cfg(feature = "kovi")]
+use crate::compat::MsgEvent;
+use crate::event::{Error, PostType};
+use crate::{__impl_serde, is_none_and_return, unexpected_value};
+
+/// 表示私聊消息的消息事件
+///
+/// 封装了私聊消息事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 Kovi 的 `MsgEvent` 或原始 JSON [`Value`] 的安全转换，如果转换失败则证明事件不是私聊消息事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{MsgEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::message::PrivateMessageEvent;
+///
+/// plugin::on_msg(|it| async move {
+///     let event = match PrivateMessageEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName)]
+pub struct PrivateMessageEvent {
+    /// 事件发生的时间戳
+    pub time: i64,
+    /// 收到事件的机器人 QQ 号
+    pub self_id: i64,
+    /// 上报类型。固定为 [`PostType::Message`]
+    pub post_type: PostType,
+    /// 消息类型。固定为 [`MessageType::Private`]
+    pub message_type: MessageType,
+    /// 消息子类型。可能为 [`SubType::Friend`]、[`SubType::Group`] 或 [`SubType::OtherSource`]
+    pub sub_type: SubType,
+    /// 消息 ID
+    pub message_id: i64,
+    /// 发送者 QQ 号
+    pub user_id: i64,
+    /// 发送者信息
+    pub sender: Sender,
+
+    /// 原始的 JSON [Value]
+    original_event: Value,
+    /// 转换前的 Kovi 事件
+    #[cfg(feature = "kovi")]
+    msg_event: Option<MsgEvent>
+}
+
+impl PrivateMessageEvent {
+    /// 如果是好友私聊则为 [`true`]
+    pub fn is_friend(&self) -> bool { self.sub_type == SubType::Friend }
+    /// 如果是群临时会话则为 [`true`]
+    pub fn is_group_temp(&self) -> bool { self.sub_type == SubType::Group }
+
+    /// 从原始 JSON 解析，与 [`TryFrom<&Value>`] 相同
+    pub fn from_json(json: &Value) -> Result<Self, Error> {
+        Self::try_from(json)
+    }
+
+    /// 转换前的 Kovi 事件。从 JSON 解析时为 None
+    #[cfg(feature = "kovi")]
+    pub fn msg_event(&self) -> Option<&MsgEvent> { self.msg_event.as_ref() }
+
+    /// 将消息内容解析为 [`Segment`] 列表
+    pub fn segments(&self) -> Result<Vec<Segment>, Error> {
+        Segment::parse_message(&self.original_event["message"])
+    }
+}
+
+impl Deref for PrivateMessageEvent {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target { &self.original_event }
+}
+
+impl PrivateMessageEvent {
+    /// 在借用的 JSON 上校验与读取字段，只在成功时克隆
+    fn parse(json: &Value, source_event: &str) -> Result<Self, Error> {
+        macro_rules! unable_convert {
+            ($it:expr) => {
+                Error::UnableConvert {
+                    source_event: String::from(source_event),
+                    target_event: String::from(Self::struct_name()),
+                    because: Box::new($it)
+                }
+            };
+        }
+
+        Ok(Self {
+            time: is_none_and_return!(json, "time", as_i64),
+            self_id: is_none_and_return!(json, "self_id", as_i64),
+            post_type: {
+                let it = PostType::try_from(is_none_and_return!(
+                    json,
+                    "post_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != PostType::Message {
+                    return Err(unable_convert!(unexpected_value!(
+                        "post_type",
+                        it,
+                        [PostType::Message]
+                    )));
+                }
+
+                it
+            },
+            message_type: {
+                let it = MessageType::try_from(is_none_and_return!(
+                    json,
+                    "message_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != MessageType::Private {
+                    return Err(unable_convert!(unexpected_value!(
+                        "message_type",
+                        it,
+                        [MessageType::Private]
+                    )));
+                }
+
+                it
+            },
+            sub_type: {
+                let it = SubType::try_from(is_none_and_return!(
+                    json, "sub_type", as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != SubType::Friend
+                    && it != SubType::Group
+                    && it != SubType::OtherSource
+                {
+                    return Err(unable_convert!(unexpected_value!(
+                        "sub_type",
+                        it,
+                        [SubType::Friend, SubType::Group, SubType::OtherSource]
+                    )));
+                }
+
+                it
+            },
+            message_id: is_none_and_return!(json, "message_id", as_i64),
+            user_id: is_none_and_return!(json, "user_id", as_i64),
+            sender: json
+                .get("sender")
+                .and_then(|it| it.as_object())
+                .map(Sender::from_json)
+                .unwrap_or_default(),
+            original_event: json.clone(),
+            #[cfg(feature = "kovi")]
+            msg_event: None
+        })
+    }
+}
+
+impl TryFrom<&Value> for PrivateMessageEvent {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Self::parse(value, "Value")
+    }
+}
+
+impl TryFrom<Value> for PrivateMessageEvent {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(feature = "kovi")]
+impl TryFrom<MsgEvent> for PrivateMessageEvent {
+    type Error = Error;
+
+    fn try_from(value: MsgEvent) -> Result<Self, Self::Error> {
+        let mut it = Self::parse(&value.original_json, "MsgEvent")?;
+        it.msg_event = Some(value);
+        Ok(it)
+    }
+}
+
+#[cfg(feature = "kovi")]
+impl TryFrom<&MsgEvent> for PrivateMessageEvent {
+    type Error = Error;
+
+    fn try_from(value: &MsgEvent) -> Result<Self, Self::Error> {
+        let mut it = Self::parse(&value.original_json, "MsgEvent")?;
+        it.msg_event = Some(value.clone());
+        Ok(it)
+    }
+}
+
+__impl_serde! {
+    PrivateMessageEvent;
+    from = crate::__private::value;
+    "time" => crate::__private::same { time },
+    "self_id" => crate::__private::same { self_id },
+    "post_type" => crate::__private::same { post_type },
+    "message_type" => crate::__private::same { message_type },
+    "sub_type" => crate::__private::same { sub_type },
+    "message_id" => crate::__private::same { message_id },
+    "user_id" => crate::__private::same { user_id },
+    "sender" => crate::__private::same { sender },
+    "message" => crate::__private::message { original_event }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
+
+/// 群成员角色
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Role {
+    /// 群主
+    Owner,
+    /// 管理员
+    Admin,
+    /// 普通成员
+    Member,
+    /// 未知的角色，保留原始字符串
+    Other(UnknownValue)
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Owner => "owner",
+            Self::Admin => "admin",
+            Self::Member => "member",
+            Self::Other(it) => it.as_str()
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl TryFrom<&str> for Role {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "owner" => Ok(Self::Owner),
+            "admin" => Ok(Self::Admin),
+            "member" => Ok(Self::Member),
+            _ => Err(Self::Error::UnknownRole(value.to_string()))
+        }
+    }
+}
+
+impl Role {
+    /// 宽松地解析角色
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`Role::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的角色则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(Role);
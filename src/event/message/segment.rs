@@ -0,0 +1,681 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use serde_json::{Map, Value, json};
+
+use crate::event::Error;
+
+/// 消息段的参数表
+pub type Data = Map<String, Value>;
+
+/// 消息段
+///
+/// 支持 OneBot 的数组格式与 CQ 码字符串格式，并且可以无损地转换回去。
+/// 除 [`Segment::Text`] 外，每个消息段都会将未识别的参数保留在 `extra` 中。
+///
+/// 已知参数以字符串保存，[`Extra`] 同时记录了参数的原始顺序与原始 JSON 类型，
+/// 因此未修改的消息段会按原样输出，例如 `{"id": 123}` 不会变成 `{"id": "123"}`。
+/// CQ 码中没有 `=` 的参数在数组格式中为 `null`。
+///
+/// ```rust,no_run
+/// use kovi_event_extra::event::message::Segment;
+///
+/// let segments = Segment::parse_cq("[CQ:at,qq=10001] 你好&#91;").unwrap();
+///
+/// for it in &segments {
+///     if let Segment::At { qq, .. } = it {
+///         println!("@{}", qq);
+///     }
+/// }
+///
+/// assert_eq!(Segment::join_cq(&segments), "[CQ:at,qq=10001] 你好&#91;");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    /// 纯文本
+    Text {
+        /// 文本内容
+        text: String
+    },
+    /// QQ 表情
+    Face {
+        /// 表情 ID
+        id: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 图片
+    Image {
+        /// 图片文件名
+        file: String,
+        /// 图片 URL
+        url: Option<String>,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 语音
+    Record {
+        /// 语音文件名
+        file: String,
+        /// 语音 URL
+        url: Option<String>,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 短视频
+    Video {
+        /// 视频文件名
+        file: String,
+        /// 视频 URL
+        url: Option<String>,
+        /// 其他参数
+        extra: Extra
+    },
+    /// @某人
+    At {
+        /// 被 @ 的 QQ 号，`all` 表示全体成员
+        qq: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 回复
+    Reply {
+        /// 回复的消息 ID
+        id: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 合并转发
+    Forward {
+        /// 合并转发 ID
+        id: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// JSON 消息
+    Json {
+        /// JSON 内容
+        data: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// XML 消息
+    Xml {
+        /// XML 内容
+        data: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 文件
+    File {
+        /// 文件名
+        file: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 戳一戳
+    Poke {
+        /// 戳一戳类型，对应参数 `type`
+        kind: String,
+        /// 戳一戳 ID
+        id: String,
+        /// 其他参数
+        extra: Extra
+    },
+    /// 掷骰子魔法表情
+    Dice {
+        /// 其他参数
+        extra: Extra
+    },
+    /// 猜拳魔法表情
+    Rps {
+        /// 其他参数
+        extra: Extra
+    },
+    /// 未知的消息段，保留原始类型与参数
+    Other {
+        /// 消息段类型
+        kind: String,
+        /// 参数
+        data: Data
+    }
+}
+
+/// 消息段的其他参数
+///
+/// 可以像 [`Data`] 一样读写。解析时还会记录所有参数的原始顺序，以及已知参数中不是字符串的原始值，
+/// 用于无损地输出；比较时只比较参数本身
+#[derive(Debug, Clone, Default)]
+pub struct Extra {
+    data: Data,
+    /// 解析时所有参数的顺序
+    order: Vec<String>,
+    /// 已知参数中不是字符串的原始值
+    raw: Data
+}
+
+impl Extra {
+    /// 按原始顺序列出已知参数与其他参数，新增的参数排在最后
+    fn fields<'a>(
+        &'a self,
+        known: &[(&'a str, Option<&'a String>)]
+    ) -> Vec<(&'a str, Value)> {
+        let known_value = |key: &str, value: &String| match self.raw.get(key) {
+            // 只在已知参数未被修改时使用原始值
+            Some(raw) if to_string(raw) == *value => raw.clone(),
+            _ => Value::String(value.clone())
+        };
+        let mut fields = Vec::new();
+
+        for key in &self.order {
+            match known.iter().find(|(it, _)| it == key) {
+                Some((key, Some(value))) => {
+                    fields.push((*key, known_value(key, value)))
+                }
+                Some((_, None)) => {}
+                None => {
+                    if let Some(value) = self.data.get(key) {
+                        fields.push((key.as_str(), value.clone()));
+                    }
+                }
+            }
+        }
+
+        for (key, value) in known {
+            match value {
+                Some(value) if !self.order.iter().any(|it| it == key) => {
+                    fields.push((*key, known_value(key, value)))
+                }
+                _ => {}
+            }
+        }
+
+        for (key, value) in &self.data {
+            if !self.order.contains(key) {
+                fields.push((key.as_str(), value.clone()));
+            }
+        }
+
+        fields
+    }
+
+    /// 取出已知参数，非字符串的值会被转换为字符串并记录原始值
+    fn take(&mut self, key: &str) -> Option<String> {
+        let value = self.data.remove(key)?;
+        let it = to_string(&value);
+
+        if !value.is_string() {
+            self.raw.insert(key.to_string(), value);
+        }
+
+        Some(it)
+    }
+
+    /// 取出必须存在的已知参数
+    fn required(
+        &mut self,
+        key: &str,
+        source_event: &str
+    ) -> Result<String, Error> {
+        self.take(key)
+            .ok_or_else(|| missing_field(source_event, key))
+    }
+}
+
+impl From<Data> for Extra {
+    fn from(value: Data) -> Self {
+        Self {
+            order: value.keys().cloned().collect(),
+            data: value,
+            raw: Data::new()
+        }
+    }
+}
+
+impl Deref for Extra {
+    type Target = Data;
+
+    fn deref(&self) -> &Self::Target { &self.data }
+}
+
+impl DerefMut for Extra {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.data }
+}
+
+impl PartialEq for Extra {
+    fn eq(&self, other: &Self) -> bool { self.data == other.data }
+}
+
+impl Segment {
+    /// 创建一个纯文本消息段
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// 获取消息段类型，与 OneBot 中的 `type` 一致
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::Text { .. } => "text",
+            Self::Face { .. } => "face",
+            Self::Image { .. } => "image",
+            Self::Record { .. } => "record",
+            Self::Video { .. } => "video",
+            Self::At { .. } => "at",
+            Self::Reply { .. } => "reply",
+            Self::Forward { .. } => "forward",
+            Self::Json { .. } => "json",
+            Self::Xml { .. } => "xml",
+            Self::File { .. } => "file",
+            Self::Poke { .. } => "poke",
+            Self::Dice { .. } => "dice",
+            Self::Rps { .. } => "rps",
+            Self::Other { kind, .. } => kind
+        }
+    }
+
+    /// 解析消息，同时支持数组格式与 CQ 码字符串格式
+    ///
+    /// 通常传入 `original_json["message"]`
+    pub fn parse_message(value: &Value) -> Result<Vec<Self>, Error> {
+        match value {
+            Value::String(it) => Self::parse_cq(it),
+            Value::Array(_) => Self::parse_array(value),
+            _ => {
+                let because = Error::WrongFieldType {
+                    field: String::from("message"),
+                    expected: String::from("array | string")
+                };
+                Err(unable_convert("Value", because))
+            }
+        }
+    }
+
+    /// 解析数组格式的消息
+    pub fn parse_array(value: &Value) -> Result<Vec<Self>, Error> {
+        let Some(array) = value.as_array() else {
+            let because = Error::WrongFieldType {
+                field: String::from("message"),
+                expected: String::from("array")
+            };
+            return Err(unable_convert("Value", because));
+        };
+
+        array.iter().map(Self::try_from).collect()
+    }
+
+    /// 解析 CQ 码格式的消息
+    ///
+    /// 未闭合的 `[CQ:` 会被视为纯文本
+    pub fn parse_cq(value: &str) -> Result<Vec<Self>, Error> {
+        let mut segments = Vec::new();
+        let mut rest = value;
+
+        while !rest.is_empty() {
+            let Some(start) = rest.find("[CQ:") else {
+                segments.push(Self::text(unescape_text(rest)));
+                break;
+            };
+            let Some(end) = rest[start..].find(']').map(|it| it + start) else {
+                segments.push(Self::text(unescape_text(rest)));
+                break;
+            };
+
+            if start > 0 {
+                segments.push(Self::text(unescape_text(&rest[..start])));
+            }
+
+            let mut params = rest[start + 4..end].split(',');
+            let kind = params.next().unwrap_or_default();
+            let data = params
+                .map(|it| match it.split_once('=') {
+                    Some((key, value)) => {
+                        (key.to_string(), Value::String(unescape_param(value)))
+                    }
+                    None => (it.to_string(), Value::Null)
+                })
+                .collect();
+
+            segments.push(Self::from_parts("str", kind, data)?);
+            rest = &rest[end + 1..];
+        }
+
+        Ok(segments)
+    }
+
+    /// 转换为数组格式中的单个消息段
+    pub fn to_json(&self) -> Value {
+        json!({ "type": self.kind(), "data": self.data_json() })
+    }
+
+    /// 将多个消息段转换为数组格式
+    pub fn join_json(segments: &[Self]) -> Value {
+        Value::Array(segments.iter().map(Self::to_json).collect())
+    }
+
+    /// 将多个消息段转换为 CQ 码格式
+    pub fn join_cq(segments: &[Self]) -> String {
+        segments.iter().map(ToString::to_string).collect()
+    }
+
+    /// 将多个消息段转换为 Kovi 的 [`Message`](kovi::Message)，用于通过 `RuntimeBot` 发送
+    #[cfg(feature = "kovi")]
+    pub fn join_message(segments: &[Self]) -> kovi::Message {
+        let segments: Vec<kovi::bot::message::Segment> =
+            segments.iter().map(Into::into).collect();
+
+        kovi::Message::from(segments)
+    }
+
+    /// 数组格式中的 `data`
+    fn data_json(&self) -> Value {
+        Value::Object(
+            self.fields()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect()
+        )
+    }
+
+    fn from_parts(
+        source_event: &str,
+        kind: &str,
+        data: Data
+    ) -> Result<Self, Error> {
+        let mut extra = Extra::from(data);
+
+        Ok(match kind {
+            "text" => Self::Text {
+                text: extra.required("text", source_event)?
+            },
+            "face" => Self::Face {
+                id: extra.required("id", source_event)?,
+                extra
+            },
+            "image" => Self::Image {
+                file: extra.required("file", source_event)?,
+                url: extra.take("url"),
+                extra
+            },
+            "record" => Self::Record {
+                file: extra.required("file", source_event)?,
+                url: extra.take("url"),
+                extra
+            },
+            "video" => Self::Video {
+                file: extra.required("file", source_event)?,
+                url: extra.take("url"),
+                extra
+            },
+            "at" => Self::At {
+                qq: extra.required("qq", source_event)?,
+                extra
+            },
+            "reply" => Self::Reply {
+                id: extra.required("id", source_event)?,
+                extra
+            },
+            "forward" => Self::Forward {
+                id: extra.required("id", source_event)?,
+                extra
+            },
+            "json" => Self::Json {
+                data: extra.required("data", source_event)?,
+                extra
+            },
+            "xml" => Self::Xml {
+                data: extra.required("data", source_event)?,
+                extra
+            },
+            "file" => Self::File {
+                file: extra.required("file", source_event)?,
+                extra
+            },
+            "poke" => Self::Poke {
+                kind: extra.required("type", source_event)?,
+                id: extra.required("id", source_event)?,
+                extra
+            },
+            "dice" => Self::Dice { extra },
+            "rps" => Self::Rps { extra },
+            _ => Self::Other {
+                kind: kind.to_string(),
+                data: extra.data
+            }
+        })
+    }
+
+    /// 按原始顺序列出所有参数
+    fn fields(&self) -> Vec<(&str, Value)> {
+        let (known, extra): (Vec<(&str, Option<&String>)>, &Extra) = match self
+        {
+            Self::Text { text } => {
+                return vec![("text", Value::String(text.clone()))];
+            }
+            Self::Other { data, .. } => {
+                return data
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.clone()))
+                    .collect();
+            }
+            Self::Face { id, extra }
+            | Self::Reply { id, extra }
+            | Self::Forward { id, extra } => (vec![("id", Some(id))], extra),
+            Self::Image { file, url, extra }
+            | Self::Record { file, url, extra }
+            | Self::Video { file, url, extra } => {
+                (vec![("file", Some(file)), ("url", url.as_ref())], extra)
+            }
+            Self::At { qq, extra } => (vec![("qq", Some(qq))], extra),
+            Self::Json { data, extra } | Self::Xml { data, extra } => {
+                (vec![("data", Some(data))], extra)
+            }
+            Self::File { file, extra } => (vec![("file", Some(file))], extra),
+            Self::Poke { kind, id, extra } => {
+                (vec![("type", Some(kind)), ("id", Some(id))], extra)
+            }
+            Self::Dice { extra } | Self::Rps { extra } => (vec![], extra)
+        };
+
+        extra.fields(&known)
+    }
+}
+
+impl TryFrom<&Value> for Segment {
+    type Error = Error;
+
+    /// 从数组格式中的单个消息段转换
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let Some(kind) = value.get("type").and_then(|it| it.as_str()) else {
+            return Err(missing_field("Value", "type"));
+        };
+        let data = value
+            .get("data")
+            .and_then(|it| it.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        Self::from_parts("Value", kind, data)
+    }
+}
+
+#[cfg(feature = "kovi")]
+impl From<&Segment> for kovi::bot::message::Segment {
+    fn from(value: &Segment) -> Self {
+        Self::new(value.kind(), value.data_json())
+    }
+}
+
+impl fmt::Display for Segment {
+    /// 输出为 CQ 码格式
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Self::Text { text } = self {
+            return f.write_str(&escape(text, false));
+        }
+
+        write!(f, "[CQ:{}", self.kind())?;
+        for (key, value) in self.fields() {
+            match value {
+                Value::Null => write!(f, ",{}", key)?,
+                it => write!(f, ",{}={}", key, escape(&to_string(&it), true))?
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+/// 序列化为数组格式中的单个消息段
+#[cfg(feature = "serde")]
+impl serde::Serialize for Segment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.to_json().serialize(serializer)
+    }
+}
+
+/// 从数组格式中的单个消息段反序列化，与 [`TryFrom<&Value>`] 进行相同的校验
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Segment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        use serde::de::Error as _;
+
+        let it = Value::deserialize(deserializer)?;
+        Self::try_from(&it).map_err(D::Error::custom)
+    }
+}
+
+fn unable_convert(source_event: &str, because: Error) -> Error {
+    Error::UnableConvert {
+        source_event: String::from(source_event),
+        target_event: String::from("Segment"),
+        because: Box::new(because)
+    }
+}
+
+fn missing_field(source_event: &str, key: &str) -> Error {
+    let because = Error::MissingField {
+        field: String::from(key)
+    };
+    unable_convert(source_event, because)
+}
+
+/// 将参数的值转换为字符串，`null` 为空字符串
+fn to_string(value: &Value) -> String {
+    match value {
+        Value::String(it) => it.clone(),
+        Value::Null => String::new(),
+        it => it.to_string()
+    }
+}
+
+fn escape(value: &str, is_param: bool) -> String {
+    let value = value
+        .replace('&', "&amp;")
+        .replace('[', "&#91;")
+        .replace(']', "&#93;");
+
+    if is_param {
+        value.replace(',', "&#44;")
+    } else {
+        value
+    }
+}
+
+/// 反转义纯文本，纯文本中的 `,` 不会被转义，因此 `&#44;` 保持原样
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("&#91;", "[")
+        .replace("&#93;", "]")
+        .replace("&amp;", "&")
+}
+
+/// 反转义参数值
+fn unescape_param(value: &str) -> String {
+    unescape_text(&value.replace("&#44;", ","))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Segment;
+
+    #[test]
+    fn cq_round_trip() {
+        let value = "你好&#91;[CQ:image,url=https://a.b/?x=1&#44;2,file=a.jpg,\
+                     flash][CQ:at,qq=10001]&amp;#44;";
+        let segments = Segment::parse_cq(value).unwrap();
+
+        assert_eq!(segments.len(), 4);
+        assert_eq!(segments[0], Segment::text("你好["));
+        let Segment::Image { file, url, extra } = &segments[1] else {
+            panic!("{:?}", segments[1]);
+        };
+        assert_eq!(file, "a.jpg");
+        assert_eq!(url.as_deref(), Some("https://a.b/?x=1,2"));
+        assert_eq!(extra.get("flash"), Some(&json!(null)));
+        assert_eq!(segments[3], Segment::text("&#44;"));
+        assert_eq!(Segment::join_cq(&segments), value);
+    }
+
+    #[test]
+    fn cq_text_keeps_comma_entity() {
+        let segments = Segment::parse_cq("a&#44;b").unwrap();
+
+        assert_eq!(segments, vec![Segment::text("a&#44;b")]);
+    }
+
+    #[test]
+    fn cq_unclosed_is_text() {
+        let segments = Segment::parse_cq("a[CQ:face,id=1").unwrap();
+
+        assert_eq!(segments, vec![Segment::text("a[CQ:face,id=1")]);
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let value = json!([
+            { "type": "text", "data": { "text": "hi" } },
+            { "type": "face", "data": { "id": 123 } },
+            { "type": "image", "data": { "url": "u", "file": "f", "sub_type": 0 } },
+            { "type": "mface", "data": { "emoji_id": "1" } }
+        ]);
+        let segments = Segment::parse_array(&value).unwrap();
+
+        assert!(
+            matches!(&segments[1], Segment::Face { id, .. } if id == "123")
+        );
+        assert_eq!(segments[3].kind(), "mface");
+        assert_eq!(
+            serde_json::to_string(&Segment::join_json(&segments)).unwrap(),
+            serde_json::to_string(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn modified_field_keeps_position() {
+        let value = json!([{ "type": "poke", "data": { "id": 1, "type": 2 } }]);
+        let mut segments = Segment::parse_array(&value).unwrap();
+
+        if let Segment::Poke { id, extra, .. } = &mut segments[0] {
+            *id = String::from("3");
+            extra.insert(String::from("name"), json!("x"));
+        }
+
+        assert_eq!(
+            serde_json::to_string(&Segment::join_json(&segments)).unwrap(),
+            r#"[{"type":"poke","data":{"id":"3","type":2,"name":"x"}}]"#
+        );
+    }
+
+    #[test]
+    fn array_missing_required() {
+        let value = json!([{ "type": "at", "data": {} }]);
+
+        assert!(Segment::parse_array(&value).is_err());
+    }
+}
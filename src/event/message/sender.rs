@@ -0,0 +1,66 @@
+use serde_json::{Map, Value};
+
+use super::Role;
+
+/// 消息发送者信息
+///
+/// OneBot 不保证各字段一定存在，因此所有字段均为 [`Option`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sender {
+    /// 发送者 QQ 号
+    pub user_id: Option<i64>,
+    /// 昵称
+    pub nickname: Option<String>,
+    /// 性别，`male`、`female` 或 `unknown`
+    pub sex: Option<String>,
+    /// 年龄
+    pub age: Option<i64>,
+    /// 群名片／备注。仅群消息
+    pub card: Option<String>,
+    /// 地区。仅群消息
+    pub area: Option<String>,
+    /// 成员等级。仅群消息
+    pub level: Option<String>,
+    /// 角色。仅群消息
+    pub role: Option<Role>,
+    /// 专属头衔。仅群消息
+    pub title: Option<String>
+}
+
+impl Sender {
+    pub(super) fn from_json(json: &Map<String, Value>) -> Self {
+        let get_str = |key: &str| {
+            json.get(key).and_then(|it| it.as_str()).map(String::from)
+        };
+
+        Self {
+            user_id: json.get("user_id").and_then(|it| it.as_i64()),
+            nickname: get_str("nickname"),
+            sex: get_str("sex"),
+            age: json.get("age").and_then(|it| it.as_i64()),
+            card: get_str("card"),
+            area: get_str("area"),
+            level: get_str("level"),
+            role: json
+                .get("role")
+                .and_then(|it| it.as_str())
+                .map(Role::parse_lenient),
+            title: get_str("title")
+        }
+    }
+
+    /// 如果发送者是群主则为 [`true`]
+    pub fn is_owner(&self) -> bool { self.role == Some(Role::Owner) }
+    /// 如果发送者是群主或管理员则为 [`true`]
+    pub fn is_admin(&self) -> bool {
+        matches!(self.role, Some(Role::Owner | Role::Admin))
+    }
+    /// 获取群名片，如果群名片为空则回退到昵称
+    pub fn display_name(&self) -> Option<&str> {
+        self.card
+            .as_deref()
+            .filter(|it| !it.is_empty())
+            .or(self.nickname.as_deref())
+    }
+}
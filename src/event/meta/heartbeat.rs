@@ -0,0 +1,224 @@
+use std::ops::Deref;
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use struct_name::StructName;
+use struct_name_macro::StructName;
+
+use super::MetaEventType;
+use crate::event::{Error, PostType};
+use crate::{
+    __impl_serde,
+    _unable_convert,
+    is_none_and_return,
+    unexpected_value
+};
+
+/// 心跳事件中的运行状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeartbeatStatus {
+    /// 当前 QQ 在线状态。为 None 时表示无法查询到在线状态
+    pub online: Option<bool>,
+    /// 状态符合预期，意味着各模块正常运行、功能正常，且 QQ 在线
+    pub good: bool,
+    /// 统计信息。并非所有实现都会提供
+    pub stat: Option<HeartbeatStat>
+}
+
+/// 心跳事件中的统计信息
+///
+/// 缺失的计数器视为 0
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HeartbeatStat {
+    /// 收包数
+    pub packet_received: u64,
+    /// 发包数
+    pub packet_sent: u64,
+    /// 丢包数
+    pub packet_lost: u64,
+    /// 接收消息数
+    pub message_received: u64,
+    /// 发送消息数
+    pub message_sent: u64,
+    /// 连接断开次数
+    pub disconnect_times: u64,
+    /// 连接丢失次数
+    pub lost_times: u64,
+    /// 最后一条消息的时间戳
+    pub last_message_time: i64
+}
+
+impl HeartbeatStat {
+    fn from_json(json: &Map<String, Value>) -> Self {
+        let get = |key: &str| json.get(key).and_then(|it| it.as_u64());
+
+        Self {
+            packet_received: get("packet_received").unwrap_or_default(),
+            packet_sent: get("packet_sent").unwrap_or_default(),
+            packet_lost: get("packet_lost").unwrap_or_default(),
+            message_received: get("message_received").unwrap_or_default(),
+            message_sent: get("message_sent").unwrap_or_default(),
+            disconnect_times: get("disconnect_times").unwrap_or_default(),
+            lost_times: get("lost_times").unwrap_or_default(),
+            last_message_time: json
+                .get("last_message_time")
+                .and_then(|it| it.as_i64())
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// 表示心跳的元事件
+///
+/// 封装了心跳事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// Kovi 不会将元事件分发给插件，因此通过 [`TryFrom`] 实现从原始 JSON [`Value`] 的安全转换，
+/// 如果转换失败则证明事件不是心跳事件：
+///
+/// ```rust,no_run
+/// use serde_json::json;
+/// use kovi_event_extra::event::meta::HeartbeatMetaEvent;
+///
+/// let json = json!({
+///     "time": 1700000000,
+///     "self_id": 10001,
+///     "post_type": "meta_event",
+///     "meta_event_type": "heartbeat",
+///     "status": { "online": true, "good": true },
+///     "interval": 5000
+/// });
+/// let event = HeartbeatMetaEvent::try_from(&json).unwrap();
+/// ```
+#[derive(Debug, Clone, StructName)]
+pub struct HeartbeatMetaEvent {
+    /// 事件发生的时间戳
+    pub time: i64,
+    /// 收到事件的机器人 QQ 号
+    pub self_id: i64,
+    /// 上报类型。固定为 [`PostType::MetaEvent`]
+    pub post_type: PostType,
+    /// 元事件类型。固定为 [`MetaEventType::Heartbeat`]
+    pub meta_event_type: MetaEventType,
+    /// 运行状态
+    pub status: HeartbeatStatus,
+    /// 到下次心跳的间隔
+    pub interval: Duration,
+
+    /// 原始的 JSON [Value]
+    original_event: Value
+}
+
+impl HeartbeatMetaEvent {
+    /// 从原始 JSON 解析，与 [`TryFrom<&Value>`] 相同
+    pub fn from_json(json: &Value) -> Result<Self, Error> {
+        Self::try_from(json)
+    }
+
+    /// 如果 QQ 在线且状态符合预期则为 [`true`]
+    pub fn is_healthy(&self) -> bool {
+        self.status.good && self.status.online != Some(false)
+    }
+}
+
+impl Deref for HeartbeatMetaEvent {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target { &self.original_event }
+}
+
+impl TryFrom<Value> for HeartbeatMetaEvent {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        macro_rules! unable_convert {
+            ($it:expr) => {
+                _unable_convert!(Value, $it)
+            };
+        }
+
+        let json = &value;
+
+        Ok(Self {
+            time: is_none_and_return!(json, "time", as_i64),
+            self_id: is_none_and_return!(json, "self_id", as_i64),
+            post_type: {
+                let it = PostType::try_from(is_none_and_return!(
+                    json,
+                    "post_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != PostType::MetaEvent {
+                    return Err(unable_convert!(unexpected_value!(
+                        "post_type",
+                        it,
+                        [PostType::MetaEvent]
+                    )));
+                }
+
+                it
+            },
+            meta_event_type: {
+                let it = MetaEventType::try_from(is_none_and_return!(
+                    json,
+                    "meta_event_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != MetaEventType::Heartbeat {
+                    return Err(unable_convert!(unexpected_value!(
+                        "meta_event_type",
+                        it,
+                        [MetaEventType::Heartbeat]
+                    )));
+                }
+
+                it
+            },
+            status: {
+                let status = is_none_and_return!(json, "status", as_object);
+
+                HeartbeatStatus {
+                    online: status.get("online").and_then(|it| it.as_bool()),
+                    good: is_none_and_return!(status, "good", as_bool),
+                    stat: status
+                        .get("stat")
+                        .and_then(|it| it.as_object())
+                        .map(HeartbeatStat::from_json)
+                }
+            },
+            interval: Duration::from_millis(is_none_and_return!(
+                json, "interval", as_u64
+            )),
+            original_event: value
+        })
+    }
+}
+
+impl TryFrom<&Value> for HeartbeatMetaEvent {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Self::try_from(value.clone())
+    }
+}
+
+#[cfg(feature = "serde")]
+fn duration_as_millis(value: &Duration) -> u64 { value.as_millis() as u64 }
+
+__impl_serde! {
+    HeartbeatMetaEvent;
+    from = crate::__private::value;
+    "time" => crate::__private::same { time },
+    "self_id" => crate::__private::same { self_id },
+    "post_type" => crate::__private::same { post_type },
+    "meta_event_type" => crate::__private::same { meta_event_type },
+    "status" => crate::__private::same { status },
+    "interval" => duration_as_millis { interval }
+}
@@ -0,0 +1,165 @@
+use std::ops::Deref;
+
+use serde_json::Value;
+use struct_name::StructName;
+use struct_name_macro::StructName;
+
+use super::{MetaEventType, SubType};
+use crate::event::{Error, PostType};
+use crate::{
+    __impl_serde,
+    _unable_convert,
+    is_none_and_return,
+    unexpected_value
+};
+
+/// 表示生命周期的元事件
+///
+/// 封装了生命周期事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// Kovi 不会将元事件分发给插件，因此通过 [`TryFrom`] 实现从原始 JSON [`Value`] 的安全转换，
+/// 如果转换失败则证明事件不是生命周期事件：
+///
+/// ```rust,no_run
+/// use serde_json::json;
+/// use kovi_event_extra::event::meta::LifecycleMetaEvent;
+///
+/// let json = json!({
+///     "time": 1700000000,
+///     "self_id": 10001,
+///     "post_type": "meta_event",
+///     "meta_event_type": "lifecycle",
+///     "sub_type": "connect"
+/// });
+/// let event = LifecycleMetaEvent::try_from(&json).unwrap();
+/// ```
+#[derive(Debug, Clone, StructName)]
+pub struct LifecycleMetaEvent {
+    /// 事件发生的时间戳
+    pub time: i64,
+    /// 收到事件的机器人 QQ 号
+    pub self_id: i64,
+    /// 上报类型。固定为 [`PostType::MetaEvent`]
+    pub post_type: PostType,
+    /// 元事件类型。固定为 [`MetaEventType::Lifecycle`]
+    pub meta_event_type: MetaEventType,
+    /// 事件子类型。可能为 [`SubType::Enable`]、[`SubType::Disable`] 或 [`SubType::Connect`]
+    pub sub_type: SubType,
+
+    /// 原始的 JSON [Value]
+    original_event: Value
+}
+
+impl LifecycleMetaEvent {
+    /// 从原始 JSON 解析，与 [`TryFrom<&Value>`] 相同
+    pub fn from_json(json: &Value) -> Result<Self, Error> {
+        Self::try_from(json)
+    }
+
+    /// 如果是 OneBot 启用则为 [`true`]
+    pub fn is_enable(&self) -> bool { self.sub_type == SubType::Enable }
+    /// 如果是 OneBot 停用则为 [`true`]
+    pub fn is_disable(&self) -> bool { self.sub_type == SubType::Disable }
+    /// 如果是 WebSocket 连接成功则为 [`true`]
+    pub fn is_connect(&self) -> bool { self.sub_type == SubType::Connect }
+}
+
+impl Deref for LifecycleMetaEvent {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target { &self.original_event }
+}
+
+impl TryFrom<Value> for LifecycleMetaEvent {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        macro_rules! unable_convert {
+            ($it:expr) => {
+                _unable_convert!(Value, $it)
+            };
+        }
+
+        let json = &value;
+
+        Ok(Self {
+            time: is_none_and_return!(json, "time", as_i64),
+            self_id: is_none_and_return!(json, "self_id", as_i64),
+            post_type: {
+                let it = PostType::try_from(is_none_and_return!(
+                    json,
+                    "post_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != PostType::MetaEvent {
+                    return Err(unable_convert!(unexpected_value!(
+                        "post_type",
+                        it,
+                        [PostType::MetaEvent]
+                    )));
+                }
+
+                it
+            },
+            meta_event_type: {
+                let it = MetaEventType::try_from(is_none_and_return!(
+                    json,
+                    "meta_event_type",
+                    as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != MetaEventType::Lifecycle {
+                    return Err(unable_convert!(unexpected_value!(
+                        "meta_event_type",
+                        it,
+                        [MetaEventType::Lifecycle]
+                    )));
+                }
+
+                it
+            },
+            sub_type: {
+                let it = SubType::try_from(is_none_and_return!(
+                    json, "sub_type", as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != SubType::Enable
+                    && it != SubType::Disable
+                    && it != SubType::Connect
+                {
+                    return Err(unable_convert!(unexpected_value!(
+                        "sub_type",
+                        it,
+                        [SubType::Enable, SubType::Disable, SubType::Connect]
+                    )));
+                }
+
+                it
+            },
+            original_event: value
+        })
+    }
+}
+
+impl TryFrom<&Value> for LifecycleMetaEvent {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Self::try_from(value.clone())
+    }
+}
+
+__impl_serde! {
+    LifecycleMetaEvent;
+    from = crate::__private::value;
+    "time" => crate::__private::same { time },
+    "self_id" => crate::__private::same { self_id },
+    "post_type" => crate::__private::same { post_type },
+    "meta_event_type" => crate::__private::same { meta_event_type },
+    "sub_type" => crate::__private::same { sub_type }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
+
+/// 元事件类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MetaEventType {
+    /// 生命周期
+    Lifecycle,
+    /// 心跳
+    Heartbeat,
+    /// 未知的元事件类型，保留原始字符串
+    Other(UnknownValue)
+}
+
+impl fmt::Display for MetaEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Lifecycle => "lifecycle",
+            Self::Heartbeat => "heartbeat",
+            Self::Other(it) => it.as_str()
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl TryFrom<&str> for MetaEventType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "lifecycle" => Ok(Self::Lifecycle),
+            "heartbeat" => Ok(Self::Heartbeat),
+            _ => Err(Self::Error::UnknownMetaEventType(value.to_string()))
+        }
+    }
+}
+
+impl MetaEventType {
+    /// 宽松地解析元事件类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`MetaEventType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的元事件类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(MetaEventType);
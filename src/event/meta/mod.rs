@@ -0,0 +1,14 @@
+//! 元事件处理模块
+//!
+//! 包含各类 OneBot 元事件的具体实现
+
+mod heartbeat;
+mod lifecycle;
+mod meta_event_type;
+mod sub_type;
+
+// 重新导出
+pub use heartbeat::{HeartbeatMetaEvent, HeartbeatStat, HeartbeatStatus};
+pub use lifecycle::LifecycleMetaEvent;
+pub use meta_event_type::MetaEventType;
+pub use sub_type::SubType;
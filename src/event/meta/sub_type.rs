@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
+
+/// 元事件子类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubType {
+    /// OneBot 启用
+    Enable,
+    /// OneBot 停用
+    Disable,
+    /// WebSocket 连接成功
+    Connect,
+    /// 未知的子类型，保留原始字符串
+    Other(UnknownValue)
+}
+
+impl fmt::Display for SubType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Enable => "enable",
+            Self::Disable => "disable",
+            Self::Connect => "connect",
+            Self::Other(it) => it.as_str()
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl TryFrom<&str> for SubType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "enable" => Ok(Self::Enable),
+            "disable" => Ok(Self::Disable),
+            "connect" => Ok(Self::Connect),
+            _ => Err(Self::Error::UnknownSubType(value.to_string()))
+        }
+    }
+}
+
+impl SubType {
+    /// 宽松地解析子类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`SubType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的子类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(SubType);
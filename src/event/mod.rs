@@ -3,9 +3,16 @@
 //! 定义事件处理的基础结构和错误类型
 
 mod error;
+mod from_field;
+pub mod message;
+pub mod meta;
 pub mod notice;
 mod post_type;
+pub mod request;
+mod unknown;
 
 // 重新导出
 pub use error::Error;
+pub use from_field::FromField;
 pub use post_type::PostType;
+pub use unknown::UnknownValue;
@@ -0,0 +1,159 @@
+use std::ops::Deref;
+
+use super::{
+    FriendAddNoticeEvent,
+    FriendRecallNoticeEvent,
+    GroupAdminNoticeEvent,
+    GroupBanNoticeEvent,
+    GroupDecreaseNoticeEvent,
+    GroupIncreaseNoticeEvent,
+    GroupRecallNoticeEvent,
+    GroupUploadNoticeEvent,
+    HonorNoticeEvent,
+    LuckyKingNoticeEvent,
+    NoticeType,
+    PokeNoticeEvent,
+    SubType
+};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 所有已知通知事件的集合
+///
+/// 通过 [`Notice::classify`] 只需根据 `notice_type` 和 `sub_type` 分派一次，
+/// 即可得到对应的通知事件，无需逐个尝试 [`TryFrom`]：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::Notice;
+///
+/// plugin::on_notice(|it| async move {
+///     match Notice::classify(it.deref()) {
+///         Notice::Poke(it) => {
+///             log::info!("{} 戳了 {}", it.user_id, it.target_id)
+///         }
+///         Notice::GroupIncrease(it) => {
+///             log::info!("{} 加入了群聊", it.user_id)
+///         }
+///         _ => {}
+///     }
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub enum Notice {
+    /// 群文件上传
+    GroupUpload(GroupUploadNoticeEvent),
+    /// 群管理员变动
+    GroupAdmin(GroupAdminNoticeEvent),
+    /// 群成员减少
+    GroupDecrease(GroupDecreaseNoticeEvent),
+    /// 群成员增加
+    GroupIncrease(GroupIncreaseNoticeEvent),
+    /// 群禁言
+    GroupBan(GroupBanNoticeEvent),
+    /// 好友添加
+    FriendAdd(FriendAddNoticeEvent),
+    /// 群消息撤回
+    GroupRecall(GroupRecallNoticeEvent),
+    /// 好友消息撤回
+    FriendRecall(FriendRecallNoticeEvent),
+    /// 戳一戳
+    Poke(PokeNoticeEvent),
+    /// 群红包运气王
+    LuckyKing(LuckyKingNoticeEvent),
+    /// 群成员荣誉变更
+    Honor(HonorNoticeEvent),
+    /// 未知或无法转换的通知事件
+    Unknown(NoticeEvent)
+}
+
+impl Notice {
+    /// 根据 `notice_type` 和 `sub_type` 将 [`NoticeEvent`] 分类为对应的通知事件
+    ///
+    /// 如果事件类型未知或转换失败，则返回 [`Notice::Unknown`]
+    pub fn classify(event: &NoticeEvent) -> Self {
+        if PostType::try_from(event.post_type.as_str()) != Ok(PostType::Notice)
+        {
+            return Self::Unknown(event.clone());
+        }
+
+        let notice_type = NoticeType::try_from(event.notice_type.as_str());
+        let sub_type = event
+            .original_json
+            .get("sub_type")
+            .and_then(|it| it.as_str())
+            .map(SubType::try_from);
+
+        let it = match (notice_type, sub_type) {
+            (Ok(NoticeType::GroupUpload), _) => {
+                GroupUploadNoticeEvent::try_from(event).map(Self::GroupUpload)
+            }
+            (Ok(NoticeType::GroupAdmin), _) => {
+                GroupAdminNoticeEvent::try_from(event).map(Self::GroupAdmin)
+            }
+            (Ok(NoticeType::GroupDecrease), _) => {
+                GroupDecreaseNoticeEvent::try_from(event)
+                    .map(Self::GroupDecrease)
+            }
+            (Ok(NoticeType::GroupIncrease), _) => {
+                GroupIncreaseNoticeEvent::try_from(event)
+                    .map(Self::GroupIncrease)
+            }
+            (Ok(NoticeType::GroupBan), _) => {
+                GroupBanNoticeEvent::try_from(event).map(Self::GroupBan)
+            }
+            (Ok(NoticeType::FriendAdd), _) => {
+                FriendAddNoticeEvent::try_from(event).map(Self::FriendAdd)
+            }
+            (Ok(NoticeType::GroupRecall), _) => {
+                GroupRecallNoticeEvent::try_from(event).map(Self::GroupRecall)
+            }
+            (Ok(NoticeType::FriendRecall), _) => {
+                FriendRecallNoticeEvent::try_from(event).map(Self::FriendRecall)
+            }
+            (Ok(NoticeType::Notify), Some(Ok(SubType::Poke))) => {
+                PokeNoticeEvent::try_from(event).map(Self::Poke)
+            }
+            (Ok(NoticeType::Notify), Some(Ok(SubType::LuckyKing))) => {
+                LuckyKingNoticeEvent::try_from(event).map(Self::LuckyKing)
+            }
+            (Ok(NoticeType::Notify), Some(Ok(SubType::Honor))) => {
+                HonorNoticeEvent::try_from(event).map(Self::Honor)
+            }
+            _ => return Self::Unknown(event.clone())
+        };
+
+        it.unwrap_or_else(|_| Self::Unknown(event.clone()))
+    }
+
+    /// 如果是未知或无法转换的通知事件则为 [`true`]
+    pub fn is_unknown(&self) -> bool { matches!(self, Self::Unknown(_)) }
+}
+
+impl From<&NoticeEvent> for Notice {
+    fn from(value: &NoticeEvent) -> Self { Self::classify(value) }
+}
+
+impl Deref for Notice {
+    type Target = NoticeEvent;
+
+    /// 获取原始的 [`NoticeEvent`] 引用
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::GroupUpload(it) => it,
+            Self::GroupAdmin(it) => it,
+            Self::GroupDecrease(it) => it,
+            Self::GroupIncrease(it) => it,
+            Self::GroupBan(it) => it,
+            Self::FriendAdd(it) => it,
+            Self::GroupRecall(it) => it,
+            Self::FriendRecall(it) => it,
+            Self::Poke(it) => it,
+            Self::LuckyKing(it) => it,
+            Self::Honor(it) => it,
+            Self::Unknown(it) => it
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use kovi_event_extra_derive::NoticeEvent;
+use struct_name_macro::StructName;
+
+use super::NoticeType;
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 表示好友添加的通知事件
+///
+/// 封装了好友添加事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是好友添加事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::FriendAddNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match FriendAddNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "friend_add")]
+pub struct FriendAddNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::FriendAdd`]
+    pub notice_type: NoticeType,
+    /// 新添加好友 QQ 号
+    pub user_id: i64,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
@@ -1,20 +1,16 @@
-use std::ops::Deref;
-
-use kovi::NoticeEvent;
-use rust_i18n::t;
-use struct_name::StructName;
+use kovi_event_extra_derive::NoticeEvent;
 use struct_name_macro::StructName;
 
 use super::{NoticeType, SubType};
-use crate::event::{Error, PostType};
-use crate::{_unable_convert, is_none_and_return};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
 
 /// 表示群管理员变动的通知事件
 ///
 /// 封装了群管理员变动事件的特定字段，并提供便捷的访问方法。
 ///
 /// # 类型转换
-/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`] 的安全转换，如果转换失败则证明事件不是群管理员变动事件：
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群管理员变动事件：
 ///
 /// ```rust,no_run
 /// use std::ops::Deref;
@@ -26,13 +22,14 @@ use crate::{_unable_convert, is_none_and_return};
 ///     let event = match GroupAdminNoticeEvent::try_from(it.deref()) {
 ///         Ok(it) => it,
 ///         Err(it) => {
-///             log::trace!("{}", it);
+///             log::trace!("{}", it.display_chain());
 ///             return;
 ///         }
 ///     };
 /// });
 /// ```
-#[derive(Debug, Clone, StructName)]
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "group_admin", sub_types = ["set", "unset"])]
 pub struct GroupAdminNoticeEvent {
     /// 上报类型。固定为 [`PostType::Notice`]
     pub post_type: PostType,
@@ -49,68 +46,55 @@ pub struct GroupAdminNoticeEvent {
     original_event: NoticeEvent
 }
 
-impl Deref for GroupAdminNoticeEvent {
-    type Target = NoticeEvent;
-
-    fn deref(&self) -> &Self::Target { &self.original_event }
-}
-
-impl TryFrom<NoticeEvent> for GroupAdminNoticeEvent {
-    type Error = Error;
-
-    fn try_from(value: NoticeEvent) -> Result<Self, Self::Error> {
-        macro_rules! unable_convert {
-            ($it:ident) => {
-                _unable_convert!(NoticeEvent, $it)
-            };
-        }
-
-        let json = &value.original_json;
-
-        Ok(Self {
-            post_type: {
-                let it = PostType::try_from(value.post_type.as_str())?;
-
-                if it != PostType::Notice {
-                    let because = t!(r#"global.ne"#, a => "post_type", b => PostType::Notice);
-                    return Err(unable_convert!(because));
-                }
-
-                it
-            },
-            notice_type: {
-                let it = NoticeType::try_from(value.notice_type.as_str())?;
-
-                if it != NoticeType::Notify {
-                    let because = t!(r#"global.ne"#, a => "notice_type", b => NoticeType::Notify);
-                    return Err(unable_convert!(because));
-                }
-
-                it
-            },
-            sub_type: {
-                let it = SubType::try_from(is_none_and_return!(
-                    json, "sub_type", as_str
-                ))?;
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use serde_json::{Value, json};
+
+    use super::GroupAdminNoticeEvent;
+    use crate::event::Error;
+    use crate::event::notice::{NoticeType, SubType};
+
+    fn event(notice_type: &str, sub_type: &str) -> Value {
+        json!({
+            "time": 1,
+            "self_id": 10000,
+            "post_type": "notice",
+            "notice_type": notice_type,
+            "sub_type": sub_type,
+            "group_id": 123,
+            "user_id": 456
+        })
+    }
 
-                if it != SubType::Set && it != SubType::Unset {
-                    let because = t!(r#"event.notice.group_admin.GroupAdminNoticeEvent.SubType"#, a => SubType::Set, b => SubType::Unset);
-                    return Err(unable_convert!(because));
-                }
+    #[test]
+    fn accepts_group_admin() {
+        let it = GroupAdminNoticeEvent::from_json(&event("group_admin", "set"))
+            .unwrap();
 
-                it
-            },
-            group_id: is_none_and_return!(json, "group_id", as_i64),
-            user_id: is_none_and_return!(json, "user_id", as_i64),
-            original_event: value
-        })
+        assert_eq!(it.notice_type, NoticeType::GroupAdmin);
+        assert_eq!(it.sub_type, SubType::Set);
+        assert_eq!(it.user_id, 456);
     }
-}
 
-impl TryFrom<&NoticeEvent> for GroupAdminNoticeEvent {
-    type Error = Error;
+    #[test]
+    fn rejects_notify() {
+        assert!(
+            GroupAdminNoticeEvent::from_json(&event("notify", "set")).is_err()
+        );
+    }
 
-    fn try_from(value: &NoticeEvent) -> Result<Self, Self::Error> {
-        Self::try_from(value.clone())
+    #[test]
+    fn rejects_unexpected_sub_type() {
+        let it =
+            GroupAdminNoticeEvent::from_json(&event("group_admin", "kick"))
+                .unwrap_err();
+
+        assert!(matches!(it, Error::UnableConvert { .. }));
+        assert!(matches!(
+            it.source().and_then(|it| it.downcast_ref::<Error>()),
+            Some(Error::UnexpectedValue { field, .. }) if field == "sub_type"
+        ));
     }
 }
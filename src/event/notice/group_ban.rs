@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use kovi_event_extra_derive::NoticeEvent;
+use serde_json::Value;
+use struct_name_macro::StructName;
+
+use super::{NoticeType, SubType};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 表示群禁言的通知事件
+///
+/// 封装了群禁言事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群禁言事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::GroupBanNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match GroupBanNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "group_ban", sub_types = ["ban", "lift_ban"])]
+pub struct GroupBanNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::GroupBan`]
+    pub notice_type: NoticeType,
+    /// 事件子类型。可能为 [`SubType::Ban`] 或 [`SubType::LiftBan`]
+    pub sub_type: SubType,
+    /// 群号
+    pub group_id: i64,
+    /// 操作者 QQ 号
+    pub operator_id: i64,
+    /// 被禁言 QQ 号。为 0 时表示全员禁言
+    pub user_id: i64,
+    /// 禁言时长。解除禁言时通常为 0
+    #[notice(with = "duration_from_secs", into = "duration_as_secs")]
+    pub duration: Duration,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
+
+impl GroupBanNoticeEvent {
+    /// 如果是禁言事件则为 [`true`]，即使禁言时长为 0
+    pub fn is_ban(&self) -> bool { self.sub_type == SubType::Ban }
+    /// 如果是解除禁言事件则为 [`true`]
+    pub fn is_lift_ban(&self) -> bool { self.sub_type == SubType::LiftBan }
+    /// 如果是全员禁言或解除全员禁言则为 [`true`]
+    pub fn is_whole_group(&self) -> bool { self.user_id == 0 }
+}
+
+fn duration_from_secs(value: &Value) -> Option<Duration> {
+    value.as_u64().map(Duration::from_secs)
+}
+
+#[cfg(feature = "serde")]
+fn duration_as_secs(value: &Duration) -> u64 { value.as_secs() }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::GroupBanNoticeEvent;
+
+    #[test]
+    fn lift_ban_with_zero_duration() {
+        let it = GroupBanNoticeEvent::from_json(&json!({
+            "time": 1,
+            "self_id": 10000,
+            "post_type": "notice",
+            "notice_type": "group_ban",
+            "sub_type": "lift_ban",
+            "group_id": 123,
+            "operator_id": 789,
+            "user_id": 456,
+            "duration": 0
+        }))
+        .unwrap();
+
+        assert!(it.is_lift_ban());
+        assert_eq!(it.duration, Duration::ZERO);
+    }
+}
@@ -1,20 +1,16 @@
-use std::ops::Deref;
-
-use kovi::NoticeEvent;
-use rust_i18n::t;
-use struct_name::StructName;
+use kovi_event_extra_derive::NoticeEvent;
 use struct_name_macro::StructName;
 
 use super::{NoticeType, SubType};
-use crate::event::{Error, PostType};
-use crate::{_unable_convert, is_none_and_return};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
 
 /// 表示群成员增加的通知事件
 ///
 /// 封装了群成员增加事件的特定字段，并提供便捷的访问方法。
 ///
 /// # 类型转换
-/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`] 的安全转换，如果转换失败则证明事件不是群成员增加事件：
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群成员增加事件：
 ///
 /// ```rust,no_run
 /// use std::ops::Deref;
@@ -26,13 +22,14 @@ use crate::{_unable_convert, is_none_and_return};
 ///     let event = match GroupIncreaseNoticeEvent::try_from(it.deref()) {
 ///         Ok(it) => it,
 ///         Err(it) => {
-///             log::trace!("{}", it);
+///             log::trace!("{}", it.display_chain());
 ///             return;
 ///         }
 ///     };
 /// });
 /// ```
-#[derive(Debug, Clone, StructName)]
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "group_increase", sub_types = ["approve", "invite"])]
 pub struct GroupIncreaseNoticeEvent {
     /// 上报类型。固定为 [`PostType::Notice`]
     pub post_type: PostType,
@@ -50,70 +47,3 @@ pub struct GroupIncreaseNoticeEvent {
     /// 原始的 [NoticeEvent]
     original_event: NoticeEvent
 }
-
-impl Deref for GroupIncreaseNoticeEvent {
-    type Target = NoticeEvent;
-
-    fn deref(&self) -> &Self::Target { &self.original_event }
-}
-
-impl TryFrom<NoticeEvent> for GroupIncreaseNoticeEvent {
-    type Error = Error;
-
-    fn try_from(value: NoticeEvent) -> Result<Self, Self::Error> {
-        macro_rules! unable_convert {
-            ($it:ident) => {
-                _unable_convert!(NoticeEvent, $it)
-            };
-        }
-
-        let json = &value.original_json;
-
-        Ok(Self {
-            post_type: {
-                let it = PostType::try_from(value.post_type.as_str())?;
-
-                if it != PostType::Notice {
-                    let because = t!(r#"global.ne"#, a => "post_type", b => PostType::Notice);
-                    return Err(unable_convert!(because));
-                }
-
-                it
-            },
-            notice_type: {
-                let it = NoticeType::try_from(value.notice_type.as_str())?;
-
-                if it != NoticeType::GroupIncrease {
-                    let because = t!(r#"global.ne"#, a => "notice_type", b => NoticeType::GroupIncrease);
-                    return Err(unable_convert!(because));
-                }
-
-                it
-            },
-            sub_type: {
-                let it = SubType::try_from(is_none_and_return!(
-                    json, "sub_type", as_str
-                ))?;
-
-                if it != SubType::Approve && it != SubType::Invite {
-                    let because = t!(r#"event.notice.group_increase.GroupIncreaseNoticeEvent.SubType"#, a => SubType::Approve, b => SubType::Invite);
-                    return Err(unable_convert!(because));
-                }
-
-                it
-            },
-            group_id: is_none_and_return!(json, "group_id", as_i64),
-            operator_id: is_none_and_return!(json, "operator_id", as_i64),
-            user_id: is_none_and_return!(json, "user_id", as_i64),
-            original_event: value
-        })
-    }
-}
-
-impl TryFrom<&NoticeEvent> for GroupIncreaseNoticeEvent {
-    type Error = Error;
-
-    fn try_from(value: &NoticeEvent) -> Result<Self, Self::Error> {
-        Self::try_from(value.clone())
-    }
-}
@@ -0,0 +1,56 @@
+use kovi_event_extra_derive::NoticeEvent;
+use struct_name_macro::StructName;
+
+use super::NoticeType;
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 表示群消息撤回的通知事件
+///
+/// 封装了群消息撤回事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群消息撤回事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::GroupRecallNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match GroupRecallNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "group_recall")]
+pub struct GroupRecallNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::GroupRecall`]
+    pub notice_type: NoticeType,
+    /// 群号
+    pub group_id: i64,
+    /// 操作者 QQ 号
+    pub operator_id: i64,
+    /// 消息发送者 QQ 号
+    pub user_id: i64,
+    /// 被撤回的消息 ID
+    pub message_id: i64,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
+
+impl GroupRecallNoticeEvent {
+    /// 如果是消息发送者撤回了自己的消息则为 [`true`]
+    pub fn is_self_recall(&self) -> bool { self.operator_id == self.user_id }
+    /// 如果是管理员撤回了他人的消息则为 [`true`]
+    pub fn is_admin_recall(&self) -> bool { self.operator_id != self.user_id }
+}
@@ -0,0 +1,76 @@
+use kovi_event_extra_derive::NoticeEvent;
+use serde_json::Value;
+use struct_name_macro::StructName;
+
+use super::NoticeType;
+use crate::compat::NoticeEvent;
+use crate::event::{FromField, PostType};
+
+/// 群文件上传事件中的文件信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupFile {
+    /// 文件 ID
+    pub id: String,
+    /// 文件名
+    pub name: String,
+    /// 文件大小（字节数）
+    pub size: u64,
+    /// busid（目前不清楚有什么作用）
+    pub busid: i64,
+    /// 文件下载链接。并非所有实现都会提供
+    pub url: Option<String>
+}
+
+impl FromField for GroupFile {
+    fn from_field(value: &Value) -> Option<Self> {
+        Some(Self {
+            id: String::from_field(value.get("id")?)?,
+            name: String::from_field(value.get("name")?)?,
+            size: u64::from_field(value.get("size")?)?,
+            busid: i64::from_field(value.get("busid")?)?,
+            url: value.get("url").and_then(String::from_field)
+        })
+    }
+}
+
+/// 表示群文件上传的通知事件
+///
+/// 封装了群文件上传事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群文件上传事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::GroupUploadNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match GroupUploadNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "group_upload")]
+pub struct GroupUploadNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::GroupUpload`]
+    pub notice_type: NoticeType,
+    /// 群号
+    pub group_id: i64,
+    /// 上传者 QQ 号
+    pub user_id: i64,
+    /// 文件信息
+    pub file: GroupFile,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
@@ -0,0 +1,49 @@
+use kovi_event_extra_derive::NoticeEvent;
+use struct_name_macro::StructName;
+
+use super::{HonorType, NoticeType, SubType};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 表示群成员荣誉变更的通知事件
+///
+/// 封装了群成员荣誉变更事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群成员荣誉变更事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::HonorNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match HonorNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "notify", sub_types = ["honor"])]
+pub struct HonorNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::Notify`]
+    pub notice_type: NoticeType,
+    /// 提示类型。固定为 [`SubType::Honor`]
+    pub sub_type: SubType,
+    /// 群号
+    pub group_id: i64,
+    /// 荣誉类型
+    pub honor_type: HonorType,
+    /// 成员 QQ 号
+    pub user_id: i64,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
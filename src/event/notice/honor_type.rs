@@ -0,0 +1,68 @@
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::event::{Error, FromField, UnknownValue};
+use crate::impl_serde_str;
+
+/// 群成员荣誉类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HonorType {
+    /// 龙王
+    Talkative,
+    /// 群聊之火
+    Performer,
+    /// 快乐源泉
+    Emotion,
+    /// 未知的荣誉类型，保留原始字符串
+    Other(UnknownValue)
+}
+
+impl fmt::Display for HonorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Talkative => "talkative",
+            Self::Performer => "performer",
+            Self::Emotion => "emotion",
+            Self::Other(it) => it.as_str()
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl TryFrom<&str> for HonorType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "talkative" => Ok(Self::Talkative),
+            "performer" => Ok(Self::Performer),
+            "emotion" => Ok(Self::Emotion),
+            _ => Err(Self::Error::UnknownHonorType(value.to_string()))
+        }
+    }
+}
+
+impl HonorType {
+    /// 宽松地解析荣誉类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`HonorType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的荣誉类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl FromField for HonorType {
+    fn from_field(value: &Value) -> Option<Self> {
+        value.as_str().map(Self::parse_lenient)
+    }
+}
+
+impl_serde_str!(HonorType);
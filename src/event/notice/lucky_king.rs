@@ -0,0 +1,49 @@
+use kovi_event_extra_derive::NoticeEvent;
+use struct_name_macro::StructName;
+
+use super::{NoticeType, SubType};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
+
+/// 表示群红包运气王的通知事件
+///
+/// 封装了群红包运气王事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是群红包运气王事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{NoticeEvent, PluginBuilder as plugin, log};
+/// use kovi_event_extra::event::notice::LuckyKingNoticeEvent;
+///
+/// plugin::on_notice(|it| async move {
+///     let event = match LuckyKingNoticeEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "notify", sub_types = ["lucky_king"])]
+pub struct LuckyKingNoticeEvent {
+    /// 上报类型。固定为 [`PostType::Notice`]
+    pub post_type: PostType,
+    /// 通知类型。固定为 [`NoticeType::Notify`]
+    pub notice_type: NoticeType,
+    /// 提示类型。固定为 [`SubType::LuckyKing`]
+    pub sub_type: SubType,
+    /// 群号
+    pub group_id: i64,
+    /// 红包发送者 QQ 号
+    pub user_id: i64,
+    /// 运气王 QQ 号
+    pub target_id: i64,
+
+    /// 原始的 [NoticeEvent]
+    original_event: NoticeEvent
+}
@@ -2,17 +2,38 @@
 //!
 //! 包含各类通知事件的具体实现
 
+mod classify;
+mod friend_add;
+mod friend_recall;
 mod group_admin;
+mod group_ban;
 mod group_decrease;
 mod group_increase;
+mod group_recall;
+mod group_upload;
+mod honor;
+mod honor_type;
+mod lucky_king;
 mod notice_type;
 mod poke;
 mod sub_type;
+mod typed;
 
 // 重新导出
+pub use classify::Notice;
+pub use friend_add::FriendAddNoticeEvent;
+pub use friend_recall::FriendRecallNoticeEvent;
 pub use group_admin::GroupAdminNoticeEvent;
+pub use group_ban::GroupBanNoticeEvent;
 pub use group_decrease::GroupDecreaseNoticeEvent;
 pub use group_increase::GroupIncreaseNoticeEvent;
+pub use group_recall::GroupRecallNoticeEvent;
+pub use group_upload::{GroupFile, GroupUploadNoticeEvent};
+pub use honor::HonorNoticeEvent;
+pub use honor_type::HonorType;
+pub use kovi_event_extra_derive::NoticeEvent;
+pub use lucky_king::LuckyKingNoticeEvent;
 pub use notice_type::NoticeType;
 pub use poke::PokeNoticeEvent;
 pub use sub_type::SubType;
+pub use typed::{NoticeDescriptor, TypedNoticeEvent};
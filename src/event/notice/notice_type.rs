@@ -1,9 +1,11 @@
 use std::fmt;
 
-use crate::event::Error;
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
 
 /// 通知事件消息类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum NoticeType {
     /// 群文件上传
     GroupUpload,
@@ -22,7 +24,9 @@ pub enum NoticeType {
     /// 好友消息撤回
     FriendRecall,
     /// 群内戳一戳、群红包运气王、群成员荣誉变更
-    Notify
+    Notify,
+    /// 未知的消息类型，保留原始字符串
+    Other(UnknownValue)
 }
 
 impl fmt::Display for NoticeType {
@@ -36,7 +40,8 @@ impl fmt::Display for NoticeType {
             Self::FriendAdd => "friend_add",
             Self::GroupRecall => "group_recall",
             Self::FriendRecall => "friend_recall",
-            Self::Notify => "notify"
+            Self::Notify => "notify",
+            Self::Other(it) => it.as_str()
         };
 
         f.write_str(s)
@@ -61,3 +66,19 @@ impl TryFrom<&str> for NoticeType {
         }
     }
 }
+
+impl NoticeType {
+    /// 宽松地解析消息类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`NoticeType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的消息类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(NoticeType);
@@ -1,20 +1,20 @@
-use std::ops::Deref;
-
-use kovi::NoticeEvent;
-use rust_i18n::t;
-use struct_name::StructName;
+#[cfg(feature = "kovi")]
+use kovi::{Message, RuntimeBot};
+use kovi_event_extra_derive::NoticeEvent;
+#[cfg(feature = "kovi")]
+use serde_json::json;
 use struct_name_macro::StructName;
 
 use super::{NoticeType, SubType};
-use crate::event::{Error, PostType};
-use crate::{_unable_convert, is_none_and_return};
+use crate::compat::NoticeEvent;
+use crate::event::PostType;
 
 /// 表示戳一戳的通知事件
 ///
 /// 封装了戳一戳事件的特定字段，并提供便捷的访问方法。
 ///
 /// # 类型转换
-/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`] 的安全转换，如果转换失败则证明事件不是戳一戳事件：
+/// 通过 [`TryFrom`] 实现从 [`NoticeEvent`](struct@NoticeEvent) 的安全转换，如果转换失败则证明事件不是戳一戳事件：
 ///
 /// ```rust,no_run
 /// use std::ops::Deref;
@@ -26,13 +26,14 @@ use crate::{_unable_convert, is_none_and_return};
 ///     let event = match PokeNoticeEvent::try_from(it.deref()) {
 ///         Ok(it) => it,
 ///         Err(it) => {
-///             log::trace!("{}", it);
+///             log::trace!("{}", it.display_chain());
 ///             return;
 ///         }
 ///     };
 /// });
 /// ```
-#[derive(Debug, Clone, StructName)]
+#[derive(Debug, Clone, StructName, NoticeEvent)]
+#[notice(type = "notify", sub_types = ["poke"])]
 pub struct PokeNoticeEvent {
     /// 上报类型。固定为 [`PostType::Notice`]
     pub post_type: PostType,
@@ -41,6 +42,7 @@ pub struct PokeNoticeEvent {
     /// 提示类型。固定为 [`SubType::Poke`]
     pub sub_type: SubType,
     /// 群号。如果为 None 则为私聊戳一戳
+    #[notice(optional)]
     pub group_id: Option<i64>,
     /// 发送者 QQ 号
     pub user_id: i64,
@@ -56,73 +58,78 @@ impl PokeNoticeEvent {
     pub fn is_group(&self) -> bool { self.group_id.is_some() }
     /// 如果戳一戳事件来自私聊则为 [`true`]
     pub fn is_private(&self) -> bool { self.group_id.is_none() }
-}
-
-impl Deref for PokeNoticeEvent {
-    type Target = NoticeEvent;
-
-    /// 获取原始的 [`NoticeEvent`] 引用
-    fn deref(&self) -> &Self::Target { &self.original_event }
-}
-
-impl TryFrom<NoticeEvent> for PokeNoticeEvent {
-    type Error = Error;
+    /// 如果被戳者是 `self_id` 则为 [`true`]，通常传入机器人自己的 QQ 号
+    pub fn is_bot_target(&self, self_id: i64) -> bool {
+        self.target_id == self_id
+    }
 
-    fn try_from(value: NoticeEvent) -> Result<Self, Self::Error> {
-        macro_rules! unable_convert {
-            ($it:ident) => {
-                _unable_convert!(NoticeEvent, $it)
-            };
+    /// 戳回发送者
+    ///
+    /// 群聊中调用 `group_poke`，私聊中调用 `friend_poke`。
+    /// 这两个 API 不属于 OneBot v11 标准，需要实现端支持（如 NapCat、Lagrange）
+    ///
+    /// ```rust,no_run
+    /// use kovi::PluginBuilder as plugin;
+    /// use kovi_event_extra::plugin::PluginBuilderExt;
+    ///
+    /// let bot = plugin::get_runtime_bot();
+    ///
+    /// plugin::on_poke(move |it| {
+    ///     let bot = bot.clone();
+    ///     async move {
+    ///         if it.is_bot_target(it.self_id) {
+    ///             it.poke_back(&bot);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "kovi")]
+    pub fn poke_back(&self, bot: &RuntimeBot) {
+        match self.group_id {
+            Some(group_id) => bot.send_api(
+                "group_poke",
+                json!({ "group_id": group_id, "user_id": self.user_id })
+            ),
+            None => {
+                bot.send_api("friend_poke", json!({ "user_id": self.user_id }))
+            }
         }
+    }
 
-        let json = &value.original_json;
-
-        Ok(Self {
-            post_type: {
-                let it = PostType::try_from(value.post_type.as_str())?;
-
-                if it != PostType::Notice {
-                    let because = t!(r#"global.ne"#, a => "post_type", b => PostType::Notice);
-                    unable_convert!(because);
-                }
-
-                it
-            },
-            notice_type: {
-                let it = NoticeType::try_from(value.notice_type.as_str())?;
-
-                if it != NoticeType::Notify {
-                    let because = t!(r#"global.ne"#, a => "notice_type", b => NoticeType::Notify);
-                    unable_convert!(because);
-                }
-
-                it
-            },
-            sub_type: {
-                let it = SubType::try_from(is_none_and_return!(
-                    json, "sub_type", as_str
-                ))?;
-
-                if it != SubType::Poke {
-                    let because =
-                        t!(r#"global.ne"#, a => "sub_type", b => SubType::Poke);
-                    return Err(unable_convert!(because));
-                }
+    /// 在戳一戳发生的位置回复消息
+    ///
+    /// 群聊中发送到群，私聊中发送给发送者
+    #[cfg(feature = "kovi")]
+    pub fn reply(&self, bot: &RuntimeBot, message: impl Into<Message>) {
+        let message: Message = message.into();
 
-                it
-            },
-            group_id: json.get("group_id").and_then(|it| it.as_i64()),
-            user_id: is_none_and_return!(json, "user_id", as_i64),
-            target_id: is_none_and_return!(json, "target_id", as_i64),
-            original_event: value
-        })
+        match self.group_id {
+            Some(group_id) => bot.send_group_msg(group_id, message),
+            None => bot.send_private_msg(self.user_id, message)
+        }
     }
 }
 
-impl TryFrom<&NoticeEvent> for PokeNoticeEvent {
-    type Error = Error;
-
-    fn try_from(value: &NoticeEvent) -> Result<Self, Self::Error> {
-        Self::try_from(value.clone())
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::PokeNoticeEvent;
+
+    #[test]
+    fn private_poke() {
+        let it = PokeNoticeEvent::from_json(&json!({
+            "time": 1,
+            "self_id": 10000,
+            "post_type": "notice",
+            "notice_type": "notify",
+            "sub_type": "poke",
+            "user_id": 456,
+            "target_id": 10000
+        }))
+        .unwrap();
+
+        assert!(it.is_private());
+        assert!(it.is_bot_target(10000));
     }
 }
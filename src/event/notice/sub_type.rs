@@ -1,9 +1,11 @@
 use std::fmt;
 
-use crate::event::Error;
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
 
 /// 通知事件子类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SubType {
     /// 设置管理员
     Set,
@@ -28,7 +30,9 @@ pub enum SubType {
     /// 群红包运气王
     LuckyKing,
     /// 群成员荣耀变更
-    Honor
+    Honor,
+    /// 未知的子类型，保留原始字符串
+    Other(UnknownValue)
 }
 
 impl fmt::Display for SubType {
@@ -45,7 +49,8 @@ impl fmt::Display for SubType {
             Self::LiftBan => "lift_ban",
             Self::Poke => "poke",
             Self::LuckyKing => "lucky_king",
-            Self::Honor => "honor"
+            Self::Honor => "honor",
+            Self::Other(it) => it.as_str()
         };
 
         f.write_str(s)
@@ -73,3 +78,19 @@ impl TryFrom<&str> for SubType {
         }
     }
 }
+
+impl SubType {
+    /// 宽松地解析子类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`SubType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的子类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(SubType);
@@ -0,0 +1,152 @@
+use serde_json::Value;
+use struct_name::StructName;
+
+use super::{NoticeType, SubType};
+use crate::compat::NoticeEvent;
+use crate::event::{Error, PostType};
+
+/// 通知事件接受的 `notice_type` 与 `sub_type`
+///
+/// 可以在常量中使用，以便在编译期构建分派表：
+///
+/// ```rust,no_run
+/// use kovi_event_extra::event::notice::{
+///     GroupBanNoticeEvent,
+///     NoticeDescriptor,
+///     NoticeType,
+///     PokeNoticeEvent,
+///     SubType,
+///     TypedNoticeEvent
+/// };
+///
+/// const TABLE: &[NoticeDescriptor] =
+///     &[PokeNoticeEvent::DESCRIPTOR, GroupBanNoticeEvent::DESCRIPTOR];
+///
+/// assert_eq!(TABLE[0].notice_type, NoticeType::Notify);
+/// assert_eq!(TABLE[0].sub_types, [SubType::Poke]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoticeDescriptor {
+    /// 接受的 `notice_type`
+    pub notice_type: NoticeType,
+    /// 接受的 `sub_type`。为空时不限制
+    pub sub_types: &'static [SubType]
+}
+
+impl NoticeDescriptor {
+    /// 接受的 [`NoticeType`]
+    pub fn notice_type(&self) -> &NoticeType { &self.notice_type }
+
+    /// 接受的 [`SubType`]。为空时不限制
+    pub fn sub_types(&self) -> &'static [SubType] { self.sub_types }
+
+    /// 只检查 `post_type`、`notice_type` 与 `sub_type`，不读取其他字段，也不会克隆事件
+    ///
+    /// 返回 [`true`] 时转换仍然可能因为其他字段缺失而失败
+    pub fn matches(&self, event: &NoticeEvent) -> bool {
+        if PostType::try_from(event.post_type.as_str()) != Ok(PostType::Notice)
+            || NoticeType::parse_lenient(&event.notice_type) != self.notice_type
+        {
+            return false;
+        }
+
+        self.sub_types.is_empty()
+            || event
+                .original_json
+                .get("sub_type")
+                .and_then(Value::as_str)
+                .is_some_and(|it| {
+                    self.sub_types.contains(&SubType::parse_lenient(it))
+                })
+    }
+}
+
+/// 可以从 [`NoticeEvent`] 转换得到的通知事件
+///
+/// 本模块中的所有通知事件都实现了该 trait，通过 [`NoticeEvent`](macro@super::NoticeEvent)
+/// 派生宏定义的事件也会自动实现（需要同时派生 `StructName`）。
+/// 实现了该 trait 的事件可以直接用于 `PluginBuilderExt::on_typed` 等泛型的分派逻辑
+pub trait TypedNoticeEvent:
+    TryFrom<NoticeEvent, Error = Error>
+    + for<'a> TryFrom<&'a NoticeEvent, Error = Error>
+    + StructName
+{
+    /// 该事件接受的 `notice_type` 与 `sub_type`
+    const DESCRIPTOR: NoticeDescriptor;
+
+    /// 在转换前快速判断事件是否可能是该类型，参见 [`NoticeDescriptor::matches`]
+    ///
+    /// ```rust,no_run
+    /// use std::ops::Deref;
+    ///
+    /// use kovi::PluginBuilder as plugin;
+    /// use kovi_event_extra::event::notice::{PokeNoticeEvent, TypedNoticeEvent};
+    ///
+    /// plugin::on_notice(|it| async move {
+    ///     if !PokeNoticeEvent::matches(it.deref()) {
+    ///         return;
+    ///     }
+    /// });
+    /// ```
+    fn matches(event: &NoticeEvent) -> bool { Self::DESCRIPTOR.matches(event) }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+    use struct_name_macro::StructName;
+
+    use crate::compat::NoticeEvent;
+    use crate::event::notice::{
+        GroupBanNoticeEvent,
+        NoticeEvent,
+        NoticeType,
+        SubType,
+        TypedNoticeEvent
+    };
+
+    /// 没有 `post_type` 等字段，并且有名为 `value` 的字段
+    #[derive(Debug, Clone, StructName, NoticeEvent)]
+    #[notice(type = "notify", sub_types = ["group_name"])]
+    struct GroupNameNoticeEvent {
+        group_id: i64,
+        #[notice(rename = "name_new")]
+        value: String,
+
+        original_event: NoticeEvent
+    }
+
+    fn event(sub_type: &str) -> Value {
+        json!({
+            "time": 1,
+            "self_id": 10000,
+            "post_type": "notice",
+            "notice_type": "notify",
+            "sub_type": sub_type,
+            "group_id": 123,
+            "name_new": "name"
+        })
+    }
+
+    #[test]
+    fn validates_without_type_fields() {
+        let it = GroupNameNoticeEvent::from_json(&event("group_name")).unwrap();
+
+        assert_eq!(it.group_id, 123);
+        assert_eq!(it.value, "name");
+        assert!(GroupNameNoticeEvent::from_json(&event("poke")).is_err());
+    }
+
+    #[test]
+    fn descriptor_uses_variants() {
+        let it = GroupBanNoticeEvent::DESCRIPTOR;
+
+        assert_eq!(it.notice_type(), &NoticeType::GroupBan);
+        assert_eq!(it.sub_types(), [SubType::Ban, SubType::LiftBan]);
+
+        let it = GroupNameNoticeEvent::DESCRIPTOR;
+
+        assert_eq!(it.notice_type, NoticeType::Notify);
+        assert_eq!(it.sub_types, [SubType::parse_lenient("group_name")]);
+    }
+}
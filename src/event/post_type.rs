@@ -1,9 +1,11 @@
 use std::fmt::{self, Display};
 
-use crate::event::Error;
+use crate::event::{Error, UnknownValue};
+use crate::impl_serde_str;
 
 /// 上报类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum PostType {
     /// 消息事件
     Message,
@@ -12,7 +14,9 @@ pub enum PostType {
     /// 通知事件
     Notice,
     /// 请求事件
-    Request
+    Request,
+    /// 未知的上报类型，保留原始字符串
+    Other(UnknownValue)
 }
 
 impl Display for PostType {
@@ -21,7 +25,8 @@ impl Display for PostType {
             Self::Message => "message",
             Self::MetaEvent => "meta_event",
             Self::Notice => "notice",
-            Self::Request => "request"
+            Self::Request => "request",
+            Self::Other(it) => it.as_str()
         };
 
         f.write_str(s)
@@ -37,7 +42,23 @@ impl TryFrom<&str> for PostType {
             "meta_event" => Ok(Self::MetaEvent),
             "notice" => Ok(Self::Notice),
             "request" => Ok(Self::Request),
-            _ => Err(Self::Error::UnknownSubType(value.to_string()))
+            _ => Err(Self::Error::UnknownPostType(value.to_string()))
         }
     }
 }
+
+impl PostType {
+    /// 宽松地解析上报类型
+    ///
+    /// 与 [`TryFrom`] 不同，未知的值不会返回错误，而是保留在 [`PostType::Other`] 中，
+    /// 并且可以通过 [`Display`](std::fmt::Display) 原样输出
+    pub fn parse_lenient(value: &str) -> Self {
+        Self::try_from(value)
+            .unwrap_or_else(|_| Self::Other(UnknownValue::new(value)))
+    }
+
+    /// 如果是未知的上报类型则为 [`true`]
+    pub fn is_other(&self) -> bool { matches!(self, Self::Other(_)) }
+}
+
+impl_serde_str!(PostType);
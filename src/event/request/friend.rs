@@ -0,0 +1,159 @@
+use std::ops::Deref;
+
+#[cfg(feature = "kovi")]
+use kovi::RuntimeBot;
+use serde_json::Value;
+use struct_name::StructName;
+use struct_name_macro::StructName;
+
+use super::RequestType;
+use crate::compat::RequestEvent;
+use crate::event::{Error, PostType};
+use crate::{
+    __impl_serde,
+    _unable_convert,
+    is_none_and_return,
+    unexpected_value
+};
+
+/// 表示加好友请求的请求事件
+///
+/// 封装了加好友请求事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`RequestEvent`] 的安全转换，如果转换失败则证明事件不是加好友请求事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{PluginBuilder as plugin, RequestEvent, log};
+/// use kovi_event_extra::event::request::FriendRequestEvent;
+///
+/// plugin::on_request(|it| async move {
+///     let event = match FriendRequestEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName)]
+pub struct FriendRequestEvent {
+    /// 上报类型。固定为 [`PostType::Request`]
+    pub post_type: PostType,
+    /// 请求类型。固定为 [`RequestType::Friend`]
+    pub request_type: RequestType,
+    /// 发送请求的 QQ 号
+    pub user_id: i64,
+    /// 验证信息
+    pub comment: String,
+    /// 请求 flag，在处理请求时需要传入
+    pub flag: String,
+
+    /// 原始的 [RequestEvent]
+    original_event: RequestEvent
+}
+
+impl FriendRequestEvent {
+    /// 从原始 JSON 解析，与 [`TryFrom<RequestEvent>`] 进行相同的校验
+    ///
+    /// 不依赖 Kovi 运行时，可以用于解析 OneBot 事件日志
+    pub fn from_json(json: &Value) -> Result<Self, Error> {
+        Self::try_from(crate::__private::request_event(json.clone())?)
+    }
+
+    /// 同意加好友请求
+    #[cfg(feature = "kovi")]
+    pub fn approve(&self, bot: &RuntimeBot) {
+        bot.set_friend_add_request(&self.flag, true, "")
+    }
+
+    /// 同意加好友请求，并设置好友备注
+    #[cfg(feature = "kovi")]
+    pub fn approve_with_remark(&self, bot: &RuntimeBot, remark: &str) {
+        bot.set_friend_add_request(&self.flag, true, remark)
+    }
+
+    /// 拒绝加好友请求
+    #[cfg(feature = "kovi")]
+    pub fn reject(&self, bot: &RuntimeBot) {
+        bot.set_friend_add_request(&self.flag, false, "")
+    }
+}
+
+impl Deref for FriendRequestEvent {
+    type Target = RequestEvent;
+
+    fn deref(&self) -> &Self::Target { &self.original_event }
+}
+
+impl TryFrom<RequestEvent> for FriendRequestEvent {
+    type Error = Error;
+
+    fn try_from(value: RequestEvent) -> Result<Self, Self::Error> {
+        macro_rules! unable_convert {
+            ($it:expr) => {
+                _unable_convert!(RequestEvent, $it)
+            };
+        }
+
+        let json = &value.original_json;
+
+        Ok(Self {
+            post_type: {
+                let it = PostType::try_from(value.post_type.as_str())
+                    .map_err(|it| unable_convert!(it))?;
+
+                if it != PostType::Request {
+                    return Err(unable_convert!(unexpected_value!(
+                        "post_type",
+                        it,
+                        [PostType::Request]
+                    )));
+                }
+
+                it
+            },
+            request_type: {
+                let it = RequestType::try_from(value.request_type.as_str())
+                    .map_err(|it| unable_convert!(it))?;
+
+                if it != RequestType::Friend {
+                    return Err(unable_convert!(unexpected_value!(
+                        "request_type",
+                        it,
+                        [RequestType::Friend]
+                    )));
+                }
+
+                it
+            },
+            user_id: is_none_and_return!(json, "user_id", as_i64),
+            comment: is_none_and_return!(json, "comment", as_str).to_string(),
+            flag: is_none_and_return!(json, "flag", as_str).to_string(),
+            original_event: value
+        })
+    }
+}
+
+impl TryFrom<&RequestEvent> for FriendRequestEvent {
+    type Error = Error;
+
+    fn try_from(value: &RequestEvent) -> Result<Self, Self::Error> {
+        Self::try_from(value.clone())
+    }
+}
+
+__impl_serde! {
+    FriendRequestEvent;
+    from = crate::__private::request_event;
+    "time" => crate::__private::same { original_event.time },
+    "self_id" => crate::__private::same { original_event.self_id },
+    "post_type" => crate::__private::same { post_type },
+    "request_type" => crate::__private::same { request_type },
+    "user_id" => crate::__private::same { user_id },
+    "comment" => crate::__private::same { comment },
+    "flag" => crate::__private::same { flag }
+}
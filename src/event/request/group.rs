@@ -0,0 +1,195 @@
+use std::ops::Deref;
+
+#[cfg(feature = "kovi")]
+use kovi::RuntimeBot;
+use serde_json::Value;
+use struct_name::StructName;
+use struct_name_macro::StructName;
+
+use super::{RequestType, SubType};
+use crate::compat::RequestEvent;
+use crate::event::{Error, PostType};
+use crate::{
+    __impl_serde,
+    _unable_convert,
+    is_none_and_return,
+    unexpected_value
+};
+
+/// 表示加群请求或邀请的请求事件
+///
+/// 封装了加群请求事件的特定字段，并提供便捷的访问方法。
+///
+/// # 类型转换
+/// 通过 [`TryFrom`] 实现从 [`RequestEvent`] 的安全转换，如果转换失败则证明事件不是加群请求事件：
+///
+/// ```rust,no_run
+/// use std::ops::Deref;
+///
+/// use kovi::{PluginBuilder as plugin, RequestEvent, log};
+/// use kovi_event_extra::event::request::GroupRequestEvent;
+///
+/// plugin::on_request(|it| async move {
+///     let event = match GroupRequestEvent::try_from(it.deref()) {
+///         Ok(it) => it,
+///         Err(it) => {
+///             log::trace!("{}", it.display_chain());
+///             return;
+///         }
+///     };
+/// });
+/// ```
+#[derive(Debug, Clone, StructName)]
+pub struct GroupRequestEvent {
+    /// 上报类型。固定为 [`PostType::Request`]
+    pub post_type: PostType,
+    /// 请求类型。固定为 [`RequestType::Group`]
+    pub request_type: RequestType,
+    /// 请求子类型。可能为 [`SubType::Add`] 或 [`SubType::Invite`]
+    pub sub_type: SubType,
+    /// 群号
+    pub group_id: i64,
+    /// 发送请求的 QQ 号
+    pub user_id: i64,
+    /// 验证信息
+    pub comment: String,
+    /// 请求 flag，在处理请求时需要传入
+    pub flag: String,
+
+    /// 原始的 [RequestEvent]
+    original_event: RequestEvent
+}
+
+impl GroupRequestEvent {
+    /// 从原始 JSON 解析，与 [`TryFrom<RequestEvent>`] 进行相同的校验
+    ///
+    /// 不依赖 Kovi 运行时，可以用于解析 OneBot 事件日志
+    pub fn from_json(json: &Value) -> Result<Self, Error> {
+        Self::try_from(crate::__private::request_event(json.clone())?)
+    }
+
+    /// 如果是加群请求则为 [`true`]
+    pub fn is_add(&self) -> bool { self.sub_type == SubType::Add }
+    /// 如果是邀请 Bot 入群则为 [`true`]
+    pub fn is_invite(&self) -> bool { self.sub_type == SubType::Invite }
+
+    /// 同意加群请求或邀请
+    ///
+    /// 会自动使用事件中的 [`Self::flag`] 和 [`Self::sub_type`]
+    #[cfg(feature = "kovi")]
+    pub fn approve(&self, bot: &RuntimeBot) {
+        bot.set_group_add_request(
+            &self.flag,
+            &self.sub_type.to_string(),
+            true,
+            ""
+        )
+    }
+
+    /// 拒绝加群请求或邀请，并附上拒绝理由
+    ///
+    /// 会自动使用事件中的 [`Self::flag`] 和 [`Self::sub_type`]
+    #[cfg(feature = "kovi")]
+    pub fn reject(&self, bot: &RuntimeBot, reason: &str) {
+        bot.set_group_add_request(
+            &self.flag,
+            &self.sub_type.to_string(),
+            false,
+            reason
+        )
+    }
+}
+
+impl Deref for GroupRequestEvent {
+    type Target = RequestEvent;
+
+    fn deref(&self) -> &Self::Target { &self.original_event }
+}
+
+impl TryFrom<RequestEvent> for GroupRequestEvent {
+    type Error = Error;
+
+    fn try_from(value: RequestEvent) -> Result<Self, Self::Error> {
+        macro_rules! unable_convert {
+            ($it:expr) => {
+                _unable_convert!(RequestEvent, $it)
+            };
+        }
+
+        let json = &value.original_json;
+
+        Ok(Self {
+            post_type: {
+                let it = PostType::try_from(value.post_type.as_str())
+                    .map_err(|it| unable_convert!(it))?;
+
+                if it != PostType::Request {
+                    return Err(unable_convert!(unexpected_value!(
+                        "post_type",
+                        it,
+                        [PostType::Request]
+                    )));
+                }
+
+                it
+            },
+            request_type: {
+                let it = RequestType::try_from(value.request_type.as_str())
+                    .map_err(|it| unable_convert!(it))?;
+
+                if it != RequestType::Group {
+                    return Err(unable_convert!(unexpected_value!(
+                        "request_type",
+                        it,
+                        [RequestType::Group]
+                    )));
+                }
+
+                it
+            },
+            sub_type: {
+                let it = SubType::try_from(is_none_and_return!(
+                    json, "sub_type", as_str
+                ))
+                .map_err(|it| unable_convert!(it))?;
+
+                if it != SubType::Add && it != SubType::Invite {
+                    return Err(unable_convert!(unexpected_value!(
+                        "sub_type",
+                        it,
+                        [SubType::Add, SubType::Invite]
+                    )));
+                }
+
+                it
+            },
+            group_id: is_none_and_return!(json, "group_id", as_i64),
+            user_id: is_none_and_return!(json, "user_id", as_i64),
+            comment: is_none_and_return!(json, "comment", as_str).to_string(),
+            flag: is_none_and_return!(json, "flag", as_str).to_string(),
+            original_event: value
+        })
+    }
+}
+
+impl TryFrom<&RequestEvent> for GroupRequestEvent {
+    type Error = Error;
+
+    fn try_from(value: &RequestEvent) -> Result<Self, Self::Error> {
+        Self::try_from(value.clone())
+    }
+}
+
+__impl_serde! {
+    GroupRequestEvent;
+    from = crate::__private::request_event;
+    "time" => crate::__private::same { original_event.time },
+    "self_id" => crate::__private::same { original_event.self_id },
+    "post_type" => crate::__private::same { post_type },
+    "request_type" => crate::__private::same { request_type },
+    "sub_type" => crate::__private::same { sub_type },
+    "group_id" => crate::__private::same { group_id },
+    "user_id" => crate::__private::same { user_id },
+    "comment" => crate::__private::same { comment },
+    "flag" => crate::__private::same { flag }
+}
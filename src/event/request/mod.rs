@@ -0,0 +1,14 @@
+//! 请求事件处理模块
+//!
+//! 包含各类请求事件的具体实现
+
+mod friend;
+mod group;
+mod request_type;
+mod sub_type;
+
+// 重新导出
+pub use friend::FriendRequestEvent;
+pub use group::GroupRequestEvent;
+pub use request_type::RequestType;
+pub use sub_type::SubType;
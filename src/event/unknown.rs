@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// 枚举中未知的原始值
+///
+/// 只能通过各枚举的 `parse_lenient` 构造，因此不会包含已知的值，
+/// 例如不会出现 `SubType::Other(..)` 中保存着 `poke` 而与 `SubType::Poke` 不相等的情况
+///
+/// 由于保存了字符串，带有 `Other` 变体的枚举都不再实现 [`Copy`]，
+/// 并且都标记为 `#[non_exhaustive]`，匹配时需要使用 `_` 分支
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownValue(Cow<'static, str>);
+
+impl UnknownValue {
+    pub(crate) fn new(value: &str) -> Self {
+        Self(Cow::Owned(value.to_string()))
+    }
+
+    /// 在常量中构造，供派生宏生成的 [`NoticeDescriptor`](super::notice::NoticeDescriptor) 使用
+    pub(crate) const fn from_static(value: &'static str) -> Self {
+        Self(Cow::Borrowed(value))
+    }
+
+    /// 获取原始字符串
+    pub fn as_str(&self) -> &str { &self.0 }
+}
+
+impl fmt::Display for UnknownValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for UnknownValue {
+    fn as_ref(&self) -> &str { &self.0 }
+}
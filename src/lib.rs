@@ -4,11 +4,31 @@
 //!
 //! ## 功能特性
 //! - 类型安全的通知事件解析
-//! - 国际化的错误消息支持
+//! - 国际化的错误消息支持（`i18n` 特性，默认启用）
 //! - 便捷的事件类型判断方法
+//! - 按事件类型订阅通知事件的插件扩展
+//! - 入群欢迎与退群告别的消息模板（`template` 特性）
+//! - 事件、枚举与消息段的序列化与反序列化（`serde` 特性）
+//! - 通过 `from_json` 直接解析 OneBot JSON，关闭默认的 `kovi` 特性后可以脱离 Kovi 运行时使用
 
-rust_i18n::i18n!();
+#[cfg(feature = "i18n")]
+rust_i18n::i18n!("language", fallback = "zh-CN");
 
+// 使派生宏生成的 `::kovi_event_extra` 路径在本 crate 内同样可用
+extern crate self as kovi_event_extra;
+
+#[doc(hidden)]
+pub mod __private;
+pub mod compat;
 pub mod event;
+#[cfg(feature = "i18n")]
+mod locale;
 #[macro_use]
 pub(crate) mod r#macro;
+#[cfg(feature = "kovi")]
+pub mod plugin;
+#[cfg(feature = "template")]
+pub mod template;
+
+#[cfg(feature = "i18n")]
+pub use locale::{available_locales, locale, set_locale};
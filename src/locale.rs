@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+/// 默认语言
+pub(crate) const DEFAULT_LOCALE: &str = "zh-CN";
+
+static LOCALE: RwLock<Cow<'static, str>> =
+    RwLock::new(Cow::Borrowed(DEFAULT_LOCALE));
+
+/// 设置错误消息使用的语言，例如 `zh-CN`、`en-US`
+///
+/// 只影响本 crate，不会修改 `rust_i18n` 的全局语言。
+/// 不支持的语言会回退到 `zh-CN`。
+///
+/// ```rust
+/// kovi_event_extra::set_locale("en-US");
+///
+/// assert_eq!(kovi_event_extra::locale(), "en-US");
+/// ```
+pub fn set_locale(locale: &str) {
+    let mut it = LOCALE.write().unwrap_or_else(|it| it.into_inner());
+    *it = Cow::Owned(locale.to_string());
+}
+
+/// 获取当前错误消息使用的语言
+pub fn locale() -> String {
+    LOCALE
+        .read()
+        .unwrap_or_else(|it| it.into_inner())
+        .to_string()
+}
+
+/// 获取所有支持的语言
+pub fn available_locales() -> Vec<&'static str> {
+    crate::_rust_i18n_available_locales()
+}
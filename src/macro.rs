@@ -1,10 +1,10 @@
 #[macro_export]
 macro_rules! _unable_convert {
-    ($event:ident, $because:ident) => {
+    ($event:ident, $because:expr) => {
         Error::UnableConvert {
             source_event: String::from(stringify!($event)),
             target_event: String::from(Self::struct_name()),
-            because: $because.to_string()
+            because: Box::new($because)
         }
     };
 }
@@ -12,11 +12,134 @@ macro_rules! _unable_convert {
 #[macro_export]
 macro_rules! is_none_and_return {
     ($json:ident, $literal:literal, $ident:ident) => {{
-        let it = $json.get($literal).and_then(|it| it.$ident());
-        if it.is_none() {
-            let because = rust_i18n::t!(r#"global.is_none"#, it => $literal);
-            unable_convert!(because);
-        }
-        it.unwrap()
+        let Some(it) = $json.get($literal) else {
+            return Err(unable_convert!(Error::MissingField {
+                field: String::from($literal)
+            }));
+        };
+        let Some(it) = it.$ident() else {
+            return Err(unable_convert!(Error::WrongFieldType {
+                field: String::from($literal),
+                expected: String::from(
+                    stringify!($ident).trim_start_matches("as_")
+                )
+            }));
+        };
+
+        it
     }};
 }
+
+#[macro_export]
+macro_rules! unexpected_value {
+    ($field:literal, $actual:expr, [$($expected:expr),+ $(,)?]) => {
+        Error::UnexpectedValue {
+            field: String::from($field),
+            expected: vec![$($expected.to_string()),+],
+            actual: $actual.to_string(),
+            because: None
+        }
+    };
+}
+
+/// 为事件结构体实现 `Serialize` 与 `Deserialize`
+///
+/// 序列化时按 OneBot 的键名输出列出的字段，`=>` 后为将字段转换为可序列化值的函数。
+/// 提供 `from` 时同时实现反序列化：先反序列化为 JSON，再经 `from` 与 [`TryFrom`] 校验。
+/// 只提供 `from` 时只实现反序列化
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    (
+        $ty:ty;
+        from = $from:path;
+        $($key:literal => $into:path { $($field:ident).+ }),* $(,)?
+    ) => {
+        $crate::__impl_serde! {
+            $ty;
+            $($key => $into { $($field).+ }),*
+        }
+        $crate::__impl_serde!($ty; from = $from);
+    };
+    ($ty:ty; from = $from:path) => {
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>
+            {
+                use $crate::__private::serde::de::Error as _;
+
+                let json = <$crate::__private::serde_json::Value as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
+
+                $from(json)
+                    .and_then(<Self as ::core::convert::TryFrom<_>>::try_from)
+                    .map_err(D::Error::custom)
+            }
+        }
+    };
+    (
+        $ty:ty;
+        $($key:literal => $into:path { $($field:ident).+ }),* $(,)?
+    ) => {
+        impl $crate::__private::serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer
+            {
+                use $crate::__private::serde::ser::SerializeMap as _;
+
+                let mut map = serializer.serialize_map(::core::option::Option::None)?;
+                $(map.serialize_entry($key, &$into(&self.$($field).+))?;)*
+                map.end()
+            }
+        }
+    };
+}
+
+/// 未启用 `serde` 特性时不生成任何代码
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    ($($tt:tt)*) => {};
+}
+
+/// 为字符串枚举实现 `Serialize` 与 `Deserialize`，使用 [`Display`](std::fmt::Display) 输出
+///
+/// 默认通过 `parse_lenient` 解析，`strict` 时通过 [`TryFrom<&str>`] 解析
+#[macro_export]
+macro_rules! impl_serde_str {
+    ($ty:ty) => {
+        $crate::impl_serde_str!(@impl $ty, |it: &str| {
+            ::core::result::Result::<_, $crate::event::Error>::Ok(<$ty>::parse_lenient(it))
+        });
+    };
+    ($ty:ty, strict) => {
+        $crate::impl_serde_str!(@impl $ty, <$ty>::try_from);
+    };
+    (@impl $ty:ty, $parse:expr) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>
+            {
+                use serde::de::Error as _;
+
+                let it = String::deserialize(deserializer)?;
+                ($parse)(it.as_str()).map_err(D::Error::custom)
+            }
+        }
+    };
+}
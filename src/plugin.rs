@@ -0,0 +1,144 @@
+//! Kovi 插件的扩展
+//!
+//! 需要启用 `kovi` 特性（默认启用）
+
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use kovi::{PluginBuilder, log};
+
+use crate::event::notice::{
+    FriendAddNoticeEvent,
+    FriendRecallNoticeEvent,
+    GroupAdminNoticeEvent,
+    GroupBanNoticeEvent,
+    GroupDecreaseNoticeEvent,
+    GroupIncreaseNoticeEvent,
+    GroupRecallNoticeEvent,
+    GroupUploadNoticeEvent,
+    HonorNoticeEvent,
+    LuckyKingNoticeEvent,
+    PokeNoticeEvent,
+    TypedNoticeEvent
+};
+
+/// 通知事件的处理闭包
+///
+/// 为所有 `Fn(Arc<E>) -> impl Future<Output = ()>` 的闭包自动实现，
+/// 使 [`PluginBuilderExt::on_typed`] 只需要指定事件类型
+pub trait NoticeHandler<E>:
+    Fn(Arc<E>) -> Self::Future + Send + Sync + 'static
+{
+    /// 闭包返回的 [`Future`]
+    type Future: Future<Output = ()> + Send + 'static;
+}
+
+impl<E, F, Fut> NoticeHandler<E> for F
+where
+    F: Fn(Arc<E>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static
+{
+    type Future = Fut;
+}
+
+macro_rules! plugin_builder_ext {
+    ($($(#[$meta:meta])* $name:ident => $event:ty),* $(,)?) => {
+        /// 为 Kovi 的 [`PluginBuilder`] 提供按通知事件类型订阅的方法
+        ///
+        /// 每个方法都包装了 [`PluginBuilder::on_notice`]，只在事件能够转换为对应类型时调用闭包，
+        /// 转换失败时以 `trace` 级别记录原因：
+        ///
+        /// ```rust,no_run
+        /// use kovi::{PluginBuilder as plugin, log};
+        /// use kovi_event_extra::plugin::PluginBuilderExt;
+        ///
+        /// plugin::on_poke(|it| async move {
+        ///     log::info!("{} 戳了 {}", it.user_id, it.target_id)
+        /// });
+        /// ```
+        ///
+        /// 对于其他实现了 [`TypedNoticeEvent`] 的事件，包括自定义的事件，可以使用 [`on_typed`](Self::on_typed)：
+        ///
+        /// ```rust,no_run
+        /// use kovi::{PluginBuilder as plugin, log};
+        /// use kovi_event_extra::event::notice::PokeNoticeEvent;
+        /// use kovi_event_extra::plugin::PluginBuilderExt;
+        ///
+        /// plugin::on_typed::<PokeNoticeEvent, _>(|it| async move {
+        ///     log::info!("{} 戳了 {}", it.user_id, it.target_id)
+        /// });
+        /// ```
+        pub trait PluginBuilderExt {
+            /// 订阅任意实现了 [`TypedNoticeEvent`] 的通知事件
+            fn on_typed<E, F>(handler: F)
+            where
+                E: TypedNoticeEvent + Send + Sync + 'static,
+                F: NoticeHandler<E>;
+
+            $(
+                $(#[$meta])*
+                fn $name<F, Fut>(handler: F)
+                where
+                    F: Fn(Arc<$event>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + 'static;
+            )*
+        }
+
+        impl PluginBuilderExt for PluginBuilder {
+            fn on_typed<E, F>(handler: F)
+            where
+                E: TypedNoticeEvent + Send + Sync + 'static,
+                F: NoticeHandler<E>
+            {
+                let handler = Arc::new(handler);
+
+                PluginBuilder::on_notice(move |event| {
+                    let handler = Arc::clone(&handler);
+
+                    async move {
+                        match E::try_from(event.deref()) {
+                            Ok(it) => handler(Arc::new(it)).await,
+                            Err(it) => log::trace!("{}", it.display_chain())
+                        }
+                    }
+                });
+            }
+
+            $(
+                fn $name<F, Fut>(handler: F)
+                where
+                    F: Fn(Arc<$event>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + 'static
+                {
+                    Self::on_typed(handler)
+                }
+            )*
+        }
+    };
+}
+
+plugin_builder_ext! {
+    /// 订阅群文件上传事件
+    on_group_upload => GroupUploadNoticeEvent,
+    /// 订阅群管理员变动事件
+    on_group_admin => GroupAdminNoticeEvent,
+    /// 订阅群成员减少事件
+    on_group_decrease => GroupDecreaseNoticeEvent,
+    /// 订阅群成员增加事件
+    on_group_increase => GroupIncreaseNoticeEvent,
+    /// 订阅群禁言事件
+    on_group_ban => GroupBanNoticeEvent,
+    /// 订阅好友添加事件
+    on_friend_add => FriendAddNoticeEvent,
+    /// 订阅群消息撤回事件
+    on_group_recall => GroupRecallNoticeEvent,
+    /// 订阅好友消息撤回事件
+    on_friend_recall => FriendRecallNoticeEvent,
+    /// 订阅戳一戳事件
+    on_poke => PokeNoticeEvent,
+    /// 订阅群红包运气王事件
+    on_lucky_king => LuckyKingNoticeEvent,
+    /// 订阅群成员荣誉变更事件
+    on_honor => HonorNoticeEvent
+}
@@ -0,0 +1,345 @@
+//! 入群欢迎与退群告别的消息模板
+//!
+//! 需要启用 `template` 特性。模板可以从 TOML 文件加载：
+//!
+//! ```toml
+//! # 所有群共用的模板
+//! [default]
+//! welcome = "欢迎 {at_user} {action}本群！"
+//! farewell = "{user} {action}本群"
+//!
+//! # 覆盖 {action} 的措辞，键为事件的 sub_type
+//! [default.actions]
+//! kick = "被 {operator} 移出了"
+//!
+//! # 单独为某个群设置的模板，未设置的部分使用 [default]
+//! [groups.123456]
+//! welcome = "Welcome {at_user}!"
+//! ```
+//!
+//! # 占位符
+//! - `{user}`、`{at_user}`：加入或离开的成员 QQ 号，后者会 @ 该成员
+//! - `{operator}`、`{at_operator}`：操作者 QQ 号，后者会 @ 操作者
+//! - `{group}`：群号
+//! - `{action}`：根据 `sub_type` 变化的措辞，其中同样可以使用除 `{action}` 外的占位符
+//!
+//! 未知的占位符会原样保留。
+//!
+//! [`Templates`] 实现了 `Deserialize`，也可以配合其他 serde 库从 YAML 等格式加载。
+//!
+//! 由于 Bot 被踢出后无法再向该群发送消息，`kick_me` 事件不会生成告别消息
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "kovi")]
+use kovi::RuntimeBot;
+use serde::{Deserialize, Serialize};
+
+use crate::event::Error;
+use crate::event::message::Segment;
+use crate::event::notice::{
+    GroupDecreaseNoticeEvent,
+    GroupIncreaseNoticeEvent,
+    SubType
+};
+
+/// 所有群的消息模板
+///
+/// ```rust,no_run
+/// use kovi::PluginBuilder as plugin;
+/// use kovi_event_extra::plugin::PluginBuilderExt;
+/// use kovi_event_extra::template::Templates;
+///
+/// let bot = plugin::get_runtime_bot();
+/// let templates = Templates::from_toml_file("greeting.toml").unwrap();
+///
+/// plugin::on_group_increase(move |it| {
+///     let bot = bot.clone();
+///     let templates = templates.clone();
+///     async move {
+///         templates.send_welcome(&bot, &it);
+///     }
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Templates {
+    /// 所有群共用的模板
+    #[serde(default)]
+    pub default: GroupTemplates,
+    /// 按群号单独设置的模板，未设置的部分使用 [`Self::default`]
+    #[serde(default)]
+    pub groups: HashMap<String, GroupTemplates>
+}
+
+/// 单个群的消息模板
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupTemplates {
+    /// 入群欢迎消息
+    pub welcome: Option<String>,
+    /// 退群告别消息
+    pub farewell: Option<String>,
+    /// 按 `sub_type` 覆盖 `{action}` 的措辞
+    #[serde(default)]
+    pub actions: HashMap<String, String>
+}
+
+/// 渲染时使用的事件信息
+struct Context<'a> {
+    user_id: i64,
+    operator_id: i64,
+    group_id: i64,
+    action: &'a str
+}
+
+impl Templates {
+    /// 从 TOML 字符串加载模板
+    pub fn from_toml_str(value: &str) -> Result<Self, Error> {
+        toml::from_str(value)
+            .map_err(|it| Error::UnableLoadTemplate(it.to_string()))
+    }
+
+    /// 从 TOML 文件加载模板
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let value = fs::read_to_string(path)
+            .map_err(|it| Error::UnableLoadTemplate(it.to_string()))?;
+
+        Self::from_toml_str(&value)
+    }
+
+    /// 渲染入群欢迎消息。如果没有可用的模板则为 None
+    pub fn welcome(
+        &self,
+        event: &GroupIncreaseNoticeEvent
+    ) -> Option<Vec<Segment>> {
+        let template = self.find(event.group_id, |it| it.welcome.as_ref())?;
+        let context = Context {
+            user_id: event.user_id,
+            operator_id: event.operator_id,
+            group_id: event.group_id,
+            action: self.action(event.group_id, &event.sub_type)
+        };
+
+        Some(render(template, &context))
+    }
+
+    /// 渲染退群告别消息。如果没有可用的模板，或是 Bot 自己被踢出，则为 None
+    pub fn farewell(
+        &self,
+        event: &GroupDecreaseNoticeEvent
+    ) -> Option<Vec<Segment>> {
+        if event.sub_type == SubType::KickMe {
+            return None;
+        }
+
+        let template = self.find(event.group_id, |it| it.farewell.as_ref())?;
+        let context = Context {
+            user_id: event.user_id,
+            operator_id: event.operator_id,
+            group_id: event.group_id,
+            action: self.action(event.group_id, &event.sub_type)
+        };
+
+        Some(render(template, &context))
+    }
+
+    /// 渲染并发送入群欢迎消息。如果没有发送则为 [`false`]
+    #[cfg(feature = "kovi")]
+    pub fn send_welcome(
+        &self,
+        bot: &RuntimeBot,
+        event: &GroupIncreaseNoticeEvent
+    ) -> bool {
+        self.welcome(event)
+            .map(|it| send_group_msg(bot, event.group_id, &it))
+            .is_some()
+    }
+
+    /// 渲染并发送退群告别消息。如果没有发送则为 [`false`]
+    #[cfg(feature = "kovi")]
+    pub fn send_farewell(
+        &self,
+        bot: &RuntimeBot,
+        event: &GroupDecreaseNoticeEvent
+    ) -> bool {
+        self.farewell(event)
+            .map(|it| send_group_msg(bot, event.group_id, &it))
+            .is_some()
+    }
+
+    /// 优先使用群单独设置的模板，否则使用默认模板
+    fn find<'a>(
+        &'a self,
+        group_id: i64,
+        get: impl Fn(&'a GroupTemplates) -> Option<&'a String>
+    ) -> Option<&'a str> {
+        self.groups
+            .get(&group_id.to_string())
+            .and_then(&get)
+            .or_else(|| get(&self.default))
+            .map(String::as_str)
+    }
+
+    fn action(&self, group_id: i64, sub_type: &SubType) -> &str {
+        let key = sub_type.to_string();
+
+        self.groups
+            .get(&group_id.to_string())
+            .and_then(|it| it.actions.get(&key))
+            .or_else(|| self.default.actions.get(&key))
+            .map(String::as_str)
+            .unwrap_or_else(|| default_action(sub_type))
+    }
+}
+
+/// `{action}` 的默认措辞
+fn default_action(sub_type: &SubType) -> &'static str {
+    match sub_type {
+        SubType::Approve => "加入了",
+        SubType::Invite => "被 {operator} 邀请加入了",
+        SubType::Leave => "离开了",
+        SubType::Kick => "被 {operator} 移出了",
+        _ => ""
+    }
+}
+
+fn render(template: &str, context: &Context) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    render_into(&mut segments, template, context, true);
+    segments
+}
+
+fn render_into(
+    segments: &mut Vec<Segment>,
+    template: &str,
+    context: &Context,
+    allow_action: bool
+) {
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        push_text(segments, &rest[..start]);
+        rest = &rest[start..];
+
+        let name = rest[1..].split_once('}').map(|(it, _)| it);
+
+        match name {
+            Some(name)
+                if render_placeholder(
+                    segments,
+                    name,
+                    context,
+                    allow_action
+                ) =>
+            {
+                rest = &rest[name.len() + 2..];
+            }
+            // 不是占位符时只输出 `{`，使后面的占位符仍然可以被识别
+            _ => {
+                push_text(segments, "{");
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    push_text(segments, rest);
+}
+
+/// 渲染占位符，如果不是已知的占位符则为 [`false`]
+fn render_placeholder(
+    segments: &mut Vec<Segment>,
+    name: &str,
+    context: &Context,
+    allow_action: bool
+) -> bool {
+    match name {
+        "user" => push_text(segments, &context.user_id.to_string()),
+        "operator" => push_text(segments, &context.operator_id.to_string()),
+        "group" => push_text(segments, &context.group_id.to_string()),
+        "at_user" => segments.push(at(context.user_id)),
+        "at_operator" => segments.push(at(context.operator_id)),
+        "action" if allow_action => {
+            render_into(segments, context.action, context, false)
+        }
+        _ => return false
+    }
+
+    true
+}
+
+/// 追加文本，与前一个文本消息段合并
+fn push_text(segments: &mut Vec<Segment>, value: &str) {
+    if value.is_empty() {
+        return;
+    }
+
+    match segments.last_mut() {
+        Some(Segment::Text { text }) => text.push_str(value),
+        _ => segments.push(Segment::text(value))
+    }
+}
+
+fn at(user_id: i64) -> Segment {
+    Segment::At {
+        qq: user_id.to_string(),
+        extra: Default::default()
+    }
+}
+
+#[cfg(feature = "kovi")]
+fn send_group_msg(bot: &RuntimeBot, group_id: i64, segments: &[Segment]) {
+    bot.send_group_msg(group_id, Segment::join_message(segments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, render};
+    use crate::event::message::Segment;
+
+    const CONTEXT: Context = Context {
+        user_id: 1,
+        operator_id: 2,
+        group_id: 3,
+        action: "被 {operator} 邀请加入了"
+    };
+
+    fn render_cq(template: &str) -> String {
+        Segment::join_cq(&render(template, &CONTEXT))
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            render_cq("欢迎 {at_user} {action}{group}"),
+            "欢迎 [CQ:at,qq=1] 被 2 邀请加入了3"
+        );
+    }
+
+    #[test]
+    fn merges_text() {
+        assert_eq!(render(" {user}!", &CONTEXT), vec![Segment::text(" 1!")]);
+    }
+
+    #[test]
+    fn stray_brace() {
+        assert_eq!(render_cq("hi { {at_user} {user}"), "hi { [CQ:at,qq=1] 1");
+        assert_eq!(render_cq("{{user}}"), "{1}");
+        assert_eq!(render_cq("{user"), "{user");
+    }
+
+    #[test]
+    fn unknown_placeholder() {
+        assert_eq!(render_cq("{name} {user}"), "{name} 1");
+    }
+
+    #[test]
+    fn action_is_not_recursive() {
+        let context = Context {
+            action: "{action}",
+            ..CONTEXT
+        };
+
+        assert_eq!(Segment::join_cq(&render("{action}", &context)), "{action}");
+    }
+}